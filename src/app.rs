@@ -1,6 +1,8 @@
 mod config;
+mod theme;
 
 pub use config::AppConfig;
+pub use theme::{PriorityColors, StatusColors, Theme, ThemeColor};
 
 use bon::Builder;
 use derive_more::Debug;
@@ -11,8 +13,10 @@ use wherror::Error;
 use crate::feat::{
     cli::CliArgs,
     external_editor::{ExternalEditor, ExternalEditorEntry},
-    issues::Issues,
+    issues::{IssueEvent, Issues, IssuesEventError},
+    log_watcher::LogWatcher,
     tui::{Event, Tui, TuiState},
+    tui_board::BoardDraw,
     tui_issue_table::IssueTableDraw,
 };
 
@@ -32,6 +36,18 @@ pub struct AppError;
 #[error(debug)]
 pub struct EventHandlerError;
 
+/// One undoable step: the event as originally recorded, and its precomputed inverse.
+///
+/// The inverse is captured at record time (see `App::record_event`/`Issues::invert_event`)
+/// rather than recomputed at undo time, since e.g. the prior status only exists in `Issues`'
+/// state up to the moment the original event is applied.
+#[derive(Debug, Clone)]
+struct UndoEntry {
+    original: IssueEvent,
+    inverse: IssueEvent,
+    description: String,
+}
+
 /// Main application struct managing the terminal UI and event loop.
 ///
 /// The application coordinates between issues, configuration, TUI state, and external
@@ -46,7 +62,20 @@ pub struct App {
 
     pub external_editor: ExternalEditor,
 
+    /// Watches `args.event_log` for modifications; see `feat::log_watcher`. Started once by
+    /// `run` and kept for the whole session, since neither `try_suspend` nor
+    /// `try_external_editor` recreates the `Tui`'s event channel. `None` until `run` starts it.
+    log_watcher: Option<LogWatcher>,
+
     should_quit: bool,
+    should_suspend: bool,
+
+    undo_stack: Vec<UndoEntry>,
+    redo_stack: Vec<UndoEntry>,
+
+    /// Events appended since the last `*.snapshot.json` write; see `append_event` and
+    /// [`Self::SNAPSHOT_INTERVAL_EVENTS`].
+    events_since_snapshot: usize,
 }
 
 /// Arguments for creating a new application instance.
@@ -71,21 +100,40 @@ impl App {
     /// Initializes the application with the given issues, command-line arguments, configuration,
     /// and TUI state. Sets up an external editor instance and initializes the quit flag to false.
     pub fn new(setup: AppNewArgs) -> Self {
+        crate::feat::issue::set_active_status_set(setup.config.status_set.clone());
+        let mut tuistate = setup.tuistate;
+        for (column, display) in &setup.config.column_display {
+            tuistate
+                .issue_table
+                .set_column_display(column.clone(), *display);
+        }
         Self {
             issues: setup.issues,
             args: setup.args,
             config: setup.config,
-            tuistate: setup.tuistate,
+            tuistate,
             external_editor: ExternalEditor::default(),
+            log_watcher: None,
             should_quit: false,
+            should_suspend: false,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            events_since_snapshot: 0,
         }
     }
 
+    /// How many events `append_event` lets accumulate before writing a fresh
+    /// `*.snapshot.json`, bounding how much `from_jsonl_file`/`load_snapshot_then_tail` ever has
+    /// to replay on the next startup.
+    const SNAPSHOT_INTERVAL_EVENTS: usize = 200;
+
     /// Initializes a new TUI backend with the provided configuration.
     ///
     /// Creates and configures a terminal interface including event handler, tick rate,
-    /// and frame rate. Enters raw mode to capture keyboard input directly and starts
-    /// the event capture system. The returned TUI instance is ready to receive events.
+    /// and frame rate, with dirty-flag driven rendering (`with_render_on_demand`) so idle
+    /// redraws don't run at `frame_rate` for nothing; see `run`. Enters raw mode to capture
+    /// keyboard input directly and starts the event capture system. The returned TUI instance is
+    /// ready to receive events.
     ///
     /// # Errors
     ///
@@ -94,7 +142,9 @@ impl App {
         let mut tui = Tui::new()
             .change_context(AppError)?
             .with_tick_rate(config.tick_rate)
-            .with_frame_rate(config.frame_rate);
+            .with_frame_rate(config.frame_rate)
+            .enable_mouse(true)
+            .with_render_on_demand(true);
         tui.enter_raw_mode()
             .change_context(AppError)
             .attach("failed to enter raw mode")?;
@@ -104,6 +154,21 @@ impl App {
         Ok(tui)
     }
 
+    /// Starts watching `self.args.event_log` against `tui`'s event channel, replacing whatever
+    /// watcher (if any) was already held.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the filesystem watcher cannot be created or registered.
+    fn start_log_watcher(&mut self, tui: &Tui) -> Result<(), Report<AppError>> {
+        self.log_watcher = Some(
+            crate::feat::log_watcher::watch(&self.args.event_log, tui.event_tx.clone())
+                .change_context(AppError)
+                .attach("failed to start watching the event log for external changes")?,
+        );
+        Ok(())
+    }
+
     /// Tears down the TUI backend and restores terminal state.
     ///
     /// Stops the event handler, exits raw mode, and exits the alternate screen, returning the
@@ -127,30 +192,54 @@ impl App {
     /// Events are processed in order: external editor checks, rendering for visual events, tick
     /// handling for timed events, and event handler processing.
     ///
+    /// The backend is built with `Tui::with_render_on_demand(true)` (see `new_backend`), so the
+    /// render-interval tick goes quiet once idle instead of redrawing at `frame_rate` regardless.
+    /// `Key`/`Mouse`/`Resize`/`LogChanged` events still draw immediately here, but that draw runs
+    /// before `handle_event` below applies the event's effect on `self`, so after `handle_event`
+    /// this also calls `tui.request_render()` for those same event kinds — that marks the screen
+    /// dirty again so the next render-interval tick (bounded by `frame_rate`) picks up the
+    /// now-applied state instead of the frame going stale until some later event arrives.
+    ///
     /// # Errors
     ///
     /// Returns an error if the TUI backend cannot be initialized, if rendering fails, or if event
     /// handling encounters an unrecoverable error.
     pub async fn run(&mut self) -> Result<(), Report<AppError>> {
         let mut tui = Self::new_backend(&self.config)?;
+        self.start_log_watcher(&tui)?;
 
         loop {
             // `tui.next().await` blocks till next event
             if let Some(ev) = tui.next().await {
                 tui = self.try_external_editor(tui)?;
-                // Determine whether to render or tick
-                match ev {
-                    Event::Render | Event::Key(_) | Event::Mouse(_) | Event::Resize(_, _) => {
-                        tui.draw(|f| {
-                            self.draw(f);
-                        })
-                        .change_context(AppError)?;
-                    }
-                    Event::Tick => {}
-                    _ => (),
+
+                // Whether this event may change what's on screen, as opposed to `Tick` (which
+                // never does) or the other bookkeeping-only variants.
+                let visual_event = matches!(
+                    ev,
+                    Event::Render
+                        | Event::Key(_)
+                        | Event::Mouse(_)
+                        | Event::Resize(_, _)
+                        | Event::LogChanged
+                );
+                if visual_event {
+                    tui.draw(|f| {
+                        self.draw(f);
+                    })
+                    .change_context(AppError)?;
                 }
 
                 self.handle_event(&ev).change_context(AppError)?;
+
+                if visual_event {
+                    tui.request_render();
+                }
+
+                if self.should_suspend {
+                    tui = self.try_suspend(tui)?;
+                    self.should_suspend = false;
+                }
             }
 
             if self.should_quit {
@@ -165,10 +254,11 @@ impl App {
 
     /// Checks for and processes pending external editor requests.
     ///
-    /// If an external editor entry is pending, this method temporarily tears down the TUI backend,
-    /// launches the system's default editor with the provided data, waits for user input, then
-    /// reinitializes the TUI backend and invokes the callback with the edited content. Returns the
-    /// reinitialized TUI instance.
+    /// If an external editor entry is pending, this method temporarily tears down the draw
+    /// surface and event capture (keeping the event channel alive so nothing queued is lost),
+    /// launches the resolved editor command with the provided data, waits for user input, then
+    /// resumes the TUI backend and invokes the callback with the edited content. Returns the
+    /// same `Tui` instance passed in.
     ///
     /// # Errors
     ///
@@ -182,20 +272,25 @@ impl App {
             callback,
         }) = self.external_editor.take()
         {
-            Self::kill_backend(tui);
+            // Only tear down the draw surface and capture task, not the event channel itself,
+            // so keypresses already queued when the editor was requested aren't discarded.
+            tui.teardown_for_handoff().change_context(AppError)?;
 
-            let result = dialoguer::Editor::default()
+            let editor_command =
+                crate::feat::external_editor::resolve_editor_command(self.config.editor.as_deref());
+            let result = dialoguer::Editor::new()
+                .executable(&editor_command)
                 .require_save(true)
                 .extension(&file_extension)
                 .edit(&data)
                 .change_context(AppError)
-                .attach("failed to gather content from external editor")?;
+                .attach_with(|| format!("failed to launch external editor '{editor_command}'"))?;
 
             callback(self, result)
                 .change_context(AppError)
                 .attach("error handling input from external editor")?;
 
-            tui = Self::new_backend(&self.config)?;
+            tui.resume_after_handoff().change_context(AppError)?;
 
             tui.draw(|f| {
                 self.draw(f);
@@ -205,6 +300,127 @@ impl App {
         Ok(tui)
     }
 
+    /// Suspends the application back to the shell, then resumes it.
+    ///
+    /// Delegates to `Tui::suspend`, which exits raw mode, raises `SIGTSTP` so the OS stops the
+    /// process and hands control back to the shell, and (once the shell later resumes the
+    /// process via `SIGCONT`, e.g. `fg`) re-enters raw mode and restarts event capture on the
+    /// same `Tui` instance — so, as with `try_external_editor`'s handoff, the log watcher (which
+    /// holds a sender for this instance's event channel) keeps working without being restarted.
+    /// This is a no-op on Windows, which has no job-control signals.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if suspending/resuming the TUI backend fails.
+    fn try_suspend(&mut self, tui: Tui) -> Result<Tui, Report<AppError>> {
+        let mut tui = tui;
+        tui.suspend().change_context(AppError)?;
+        tui.draw(|f| {
+            self.draw(f);
+        })
+        .change_context(AppError)?;
+        Ok(tui)
+    }
+
+    /// Appends `event` to the event log and projects it into `self.issues`, then fires any
+    /// hooks configured for its kind (see `feat::hooks::HookConfig`) and, every
+    /// [`Self::SNAPSHOT_INTERVAL_EVENTS`] appends, refreshes the log's `*.snapshot.json`.
+    ///
+    /// A hook failing to start, or a snapshot write failing, doesn't fail this call or lose the
+    /// event it reacted to; both are instead recorded via `self.tuistate.set_status_message` for
+    /// the next render to surface. A failed snapshot write also isn't retried until the next
+    /// `SNAPSHOT_INTERVAL_EVENTS`-event boundary, same as a successful one.
+    ///
+    /// Low-level: doesn't touch the undo/redo stacks. Call sites recording a fresh user action
+    /// should use `record_event` instead; this exists so `undo`/`redo` can append their
+    /// (already-computed) inverse/original events without recursing back into undo tracking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if appending to the event log fails.
+    fn append_event(
+        &mut self,
+        event: impl Into<IssueEvent>,
+    ) -> Result<(), Report<IssuesEventError>> {
+        let event = event.into();
+        self.issues
+            .append_to_log(&self.args.event_log, event.clone())?;
+        if let Err(warning) = self.config.hooks.fire(&event) {
+            self.tuistate.set_status_message(warning);
+        }
+
+        self.events_since_snapshot += 1;
+        if self.events_since_snapshot >= Self::SNAPSHOT_INTERVAL_EVENTS {
+            self.events_since_snapshot = 0;
+            if let Err(error) = self.issues.write_snapshot(&self.args.event_log) {
+                self.tuistate
+                    .set_status_message(format!("failed to write snapshot: {error:?}"));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Records a user-originated event (see `append_event`). If `Issues::invert_event` knows how
+    /// to reverse this kind of event, captures its inverse against the pre-event state and pushes
+    /// it onto the undo stack, clearing the redo stack (a fresh action invalidates any pending
+    /// redo, same as in most editors).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if appending to the event log fails.
+    pub fn record_event(
+        &mut self,
+        event: impl Into<IssueEvent>,
+    ) -> Result<(), Report<IssuesEventError>> {
+        let event = event.into();
+        if let Some((inverse, description)) = self.issues.invert_event(&event) {
+            self.redo_stack.clear();
+            self.undo_stack.push(UndoEntry {
+                original: event.clone(),
+                inverse,
+                description,
+            });
+        }
+        self.append_event(event)
+    }
+
+    /// Undoes the most recently recorded undoable event (`u` on the issue table): appends its
+    /// precomputed inverse, moves the entry to the redo stack, and shows a transient
+    /// "undid: ..." status-line message. A no-op if there's nothing to undo.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if appending the inverse event fails.
+    pub fn undo(&mut self) -> Result<(), Report<IssuesEventError>> {
+        let Some(entry) = self.undo_stack.pop() else {
+            return Ok(());
+        };
+        self.append_event(entry.inverse.clone())?;
+        self.tuistate
+            .set_status_message(format!("undid: {}", entry.description));
+        self.redo_stack.push(entry);
+        Ok(())
+    }
+
+    /// Redoes the most recently undone event (`Ctrl+R` on the issue table): re-appends the
+    /// original event, moves the entry back to the undo stack, and shows a transient
+    /// "redid: ..." status-line message. A no-op if there's nothing to redo.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if appending the original event fails.
+    pub fn redo(&mut self) -> Result<(), Report<IssuesEventError>> {
+        let Some(entry) = self.redo_stack.pop() else {
+            return Ok(());
+        };
+        self.append_event(entry.original.clone())?;
+        self.tuistate
+            .set_status_message(format!("redid: {}", entry.description));
+        self.undo_stack.push(entry);
+        Ok(())
+    }
+
     /// Renders the application UI to the provided frame.
     ///
     /// Draws the current page's UI components based on the application's TUI state. The rendering
@@ -218,6 +434,7 @@ impl App {
         let buf = frame.buffer_mut();
         match self.tuistate.page() {
             Page::IssueTable => IssueTableDraw::render(self, area, buf),
+            Page::Board => BoardDraw::render(self, area, buf),
         }
     }
 
@@ -231,22 +448,47 @@ impl App {
     ///
     /// Returns an error if the event cannot be processed due to invalid state or event data.
     pub fn handle_event(&mut self, event: &Event) -> Result<(), Report<EventHandlerError>> {
-        use crate::feat::tui::{EventPropagation, KeyCode, Page};
+        use crate::feat::keymap::{Action, Context};
+        use crate::feat::tui::{EventExt, EventPropagation, Page};
+        use crate::feat::tui_board::BoardPageInput;
         use crate::feat::tui_issue_table::IssueTablePageInput;
 
+        // An `Event::Suspend` sent into the event channel short-circuits page handling entirely.
+        // The `Tui` event loop itself never sends one; `Ctrl+z` arrives as a key event that the
+        // keymap resolves to `Action::Suspend` further down.
+        if matches!(event, Event::Suspend) {
+            self.should_suspend = true;
+            return Ok(());
+        }
+
+        // `feat::log_watcher` noticed the event log changed; pick up any newly appended lines
+        // (none when the change was this process's own append) and let the already-scheduled
+        // redraw (see `run`) show them.
+        if matches!(event, Event::LogChanged) {
+            self.issues
+                .reload_incremental(&self.args.event_log)
+                .change_context(EventHandlerError)?;
+            return Ok(());
+        }
+
         // Match only on the page. The page input handler will manage the focus.
         let propagation = match self.tuistate.page() {
             Page::IssueTable => {
                 IssueTablePageInput::handle(self, event).change_context(EventHandlerError)?
             }
+            Page::Board => BoardPageInput::handle(self, event).change_context(EventHandlerError)?,
         };
 
         match propagation {
             EventPropagation::Continue => {
-                // Handle top-level keystrokes here
-                match event {
-                    Event::Key(key) if key.code == KeyCode::Char('q') => self.should_quit = true,
-                    _ => (),
+                // Handle top-level keystrokes here. These are looked up in the `IssueTable`
+                // context since quitting and suspending make sense regardless of focus.
+                if let (Some(key), Some(mods)) = (event.keypress(), event.modifiers()) {
+                    match self.config.keymap.resolve(Context::IssueTable, key, mods) {
+                        Some(Action::Quit) => self.should_quit = true,
+                        Some(Action::Suspend) => self.should_suspend = true,
+                        _ => (),
+                    }
                 }
             }
             EventPropagation::Stop => (),
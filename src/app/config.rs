@@ -1,6 +1,22 @@
+use std::{collections::HashMap, path::Path};
+
 use bon::Builder;
+use error_stack::{Report, ResultExt};
+use serde::Deserialize;
+use wherror::Error;
+
+use crate::{
+    app::Theme,
+    feat::{
+        hooks::HookConfig,
+        issue::StatusSet,
+        keymap::Keymap,
+        tui_issue_table::{Column, ColumnDisplay, MissingValueOrder},
+    },
+};
 
-#[derive(Builder, smart_default::SmartDefault, Debug, Clone)]
+#[derive(Builder, smart_default::SmartDefault, Debug, Clone, Deserialize)]
+#[serde(default)]
 pub struct AppConfig {
     #[default(4.0)]
     #[builder(default = 4.0)]
@@ -9,4 +25,120 @@ pub struct AppConfig {
     #[default(10.0)]
     #[builder(default = 10.0)]
     pub frame_rate: f64,
+
+    /// Keybinding map resolving `KeyCode`+`KeyModifiers` to a named `Action` per page.
+    ///
+    /// Defaults to the bindings that shipped before this was configurable; a user config
+    /// file only needs to list the bindings it wants to change.
+    #[builder(default)]
+    pub keymap: Keymap,
+
+    /// Overrides the external editor command used for comments, new issues, and column edits.
+    ///
+    /// When unset, falls back to `$VISUAL`, then `$EDITOR`, then a platform default. See
+    /// `feat::external_editor::resolve_editor_command`.
+    #[builder(into, default)]
+    pub editor: Option<String>,
+
+    /// Identity recorded as the author of new comments and issues.
+    ///
+    /// When unset, falls back to `$GIT_AUTHOR_EMAIL`, then the global git config, then the
+    /// OS username. See `AppConfig::resolve_comment_author`.
+    #[builder(default)]
+    pub user: UserConfig,
+
+    /// Where an issue missing a sorted `Column::Custom` field lands: `First` or `Last` (default).
+    ///
+    /// This placement is pinned regardless of sort direction. See
+    /// `tui_issue_table::apply_issue_sort`.
+    #[builder(default)]
+    pub custom_sort_missing: MissingValueOrder,
+
+    /// The ordered set of workflow states issues can be in, plus each state's accepted aliases.
+    ///
+    /// Defaults to the built-in `Open`/`Closed` set with their historical aliases, so existing
+    /// JSONL event logs that only ever recorded those two states still deserialize. See
+    /// `feat::issue::Status`.
+    #[builder(default)]
+    pub status_set: StatusSet,
+
+    /// Colors used by the issue table: header/selected-header/row-highlight/filter-prefix, plus
+    /// per-status and per-priority value colors. See `app::Theme`.
+    #[builder(default)]
+    pub theme: Theme,
+
+    /// Shell commands to run when an issue is created, its status changes, or a comment is
+    /// added. Empty by default. See `feat::hooks::HookConfig`.
+    #[builder(default)]
+    pub hooks: HookConfig,
+
+    /// Per-column alignment/width overrides for the issue table, keyed by column name (same
+    /// names `Column::FromStr` accepts; unrecognized names address a `Custom` field).
+    ///
+    /// Empty by default, in which case every column renders with `Column::default_display`. A
+    /// user config only needs to list the columns it wants to change, e.g. widening a
+    /// `Custom` field or center-aligning `Priority`. See `tui_issue_table::IssueTableState::
+    /// set_column_display`.
+    #[builder(default)]
+    pub column_display: HashMap<Column, ColumnDisplay>,
+}
+
+/// User-identity section of [`AppConfig`].
+#[derive(Default, Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct UserConfig {
+    pub name: Option<String>,
+    pub email: Option<String>,
+}
+
+/// Error type for config file loading failures.
+#[derive(Debug, Error)]
+#[error(debug)]
+pub struct ConfigLoadError;
+
+impl AppConfig {
+    /// Loads configuration from a RON config file, falling back to `AppConfig::default()`
+    /// if the file does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be read, or if its contents cannot
+    /// be deserialized into an `AppConfig`.
+    pub fn load_from_file<P>(path: P) -> Result<Self, Report<ConfigLoadError>>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .change_context(ConfigLoadError)
+            .attach_with(|| format!("failed to read config file {:?}", path.display()))?;
+        ron::de::from_str(&content)
+            .change_context(ConfigLoadError)
+            .attach_with(|| format!("failed to parse config file {:?}", path.display()))
+    }
+
+    /// Resolves the identity that should be recorded as the author of a new comment or issue.
+    ///
+    /// Resolution order: `user.email` from this config → `$GIT_AUTHOR_EMAIL` → the global git
+    /// config's `user.email` → the OS username. The first step that yields a non-empty value
+    /// wins, so an event log entry always has some author even without any config at all.
+    pub fn resolve_comment_author(&self) -> String {
+        if let Some(email) = self.user.email.as_ref().filter(|e| !e.is_empty()) {
+            return email.clone();
+        }
+        if let Ok(email) = std::env::var("GIT_AUTHOR_EMAIL")
+            && !email.is_empty()
+        {
+            return email;
+        }
+        if let Ok(info) = crate::common::git_user_info()
+            && !info.email.is_empty()
+        {
+            return info.email;
+        }
+        whoami::username()
+    }
 }
@@ -0,0 +1,158 @@
+use std::{collections::HashMap, str::FromStr};
+
+use ratatui::style::Color;
+use serde::{Deserialize, Deserializer, de::Visitor};
+
+use crate::feat::issue::{Priority, Status};
+
+/// A `ratatui::style::Color`, deserialized via `Color`'s own string parsing (named colors like
+/// `"red"`/`"lightblue"`, `#rrggbb` hex, or a 0-255 index) rather than relying on ratatui's own
+/// `serde` support, so `Theme`'s config format doesn't depend on that being enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThemeColor(pub Color);
+
+impl From<ThemeColor> for Color {
+    fn from(color: ThemeColor) -> Self {
+        color.0
+    }
+}
+
+impl<'de> Deserialize<'de> for ThemeColor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ThemeColorVisitor;
+
+        impl Visitor<'_> for ThemeColorVisitor {
+            type Value = ThemeColor;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a color name, `#rrggbb` hex code, or 0-255 index")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Color::from_str(value)
+                    .map(ThemeColor)
+                    .map_err(|()| E::custom(format!("invalid color '{value}'")))
+            }
+        }
+
+        deserializer.deserialize_str(ThemeColorVisitor)
+    }
+}
+
+/// Per-`Priority` value colors, used by `IssueTableDraw::render` to color `Column::Priority`
+/// cells. Flat fields (rather than a `HashMap`) since `Priority` is a fixed, closed set of
+/// variants, unlike the user-configurable [`StatusColors`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PriorityColors {
+    pub trivial: ThemeColor,
+    pub low: ThemeColor,
+    pub medium: ThemeColor,
+    pub high: ThemeColor,
+    pub critical: ThemeColor,
+    pub blocker: ThemeColor,
+}
+
+impl PriorityColors {
+    pub fn color_for(&self, priority: Priority) -> Color {
+        match priority {
+            Priority::Trivial => self.trivial,
+            Priority::Low => self.low,
+            Priority::Medium => self.medium,
+            Priority::High => self.high,
+            Priority::Critical => self.critical,
+            Priority::Blocker => self.blocker,
+        }
+        .into()
+    }
+}
+
+impl Default for PriorityColors {
+    fn default() -> Self {
+        Self {
+            trivial: ThemeColor(Color::DarkGray),
+            low: ThemeColor(Color::Gray),
+            medium: ThemeColor(Color::White),
+            high: ThemeColor(Color::Yellow),
+            critical: ThemeColor(Color::Red),
+            blocker: ThemeColor(Color::LightRed),
+        }
+    }
+}
+
+/// Per-status-name value colors, used by `IssueTableDraw::render` to color `Column::Status`
+/// cells. Keyed by display name (not enum variant, since `Status` is an index into a
+/// user-configurable `StatusSet` — see `feat::issue::Status`) with `default` covering any
+/// status without an explicit entry.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct StatusColors {
+    pub default: ThemeColor,
+    pub by_name: HashMap<String, ThemeColor>,
+}
+
+impl StatusColors {
+    pub fn color_for(&self, status: Status) -> Color {
+        self.by_name
+            .get(&status.name())
+            .copied()
+            .unwrap_or(self.default)
+            .into()
+    }
+}
+
+impl Default for StatusColors {
+    /// Dims the built-in `Closed` state; every other status (including any the user adds) falls
+    /// back to `default`, which reuses the terminal's normal foreground.
+    fn default() -> Self {
+        Self {
+            default: ThemeColor(Color::Reset),
+            by_name: HashMap::from([("Closed".to_string(), ThemeColor(Color::DarkGray))]),
+        }
+    }
+}
+
+/// Visual theme for `IssueTableDraw::render`, loaded from `AppConfig::theme` so the issue table
+/// adapts to the user's terminal palette and preference instead of the hardcoded colors it used
+/// to draw with.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    pub header_fg: ThemeColor,
+    pub header_bg: ThemeColor,
+    pub selected_header_fg: ThemeColor,
+    pub selected_header_bg: ThemeColor,
+    pub row_highlight_fg: ThemeColor,
+    pub row_highlight_bg: ThemeColor,
+    pub marked_row_fg: ThemeColor,
+    pub filter_prefix_fg: ThemeColor,
+    /// Color for the filter prefix (`/`) when the filter box's query fails to parse (see
+    /// `tui_issue_table::query_has_error`), e.g. an unterminated quote.
+    pub filter_error_fg: ThemeColor,
+    pub status_colors: StatusColors,
+    pub priority_colors: PriorityColors,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            header_fg: ThemeColor(Color::Yellow),
+            header_bg: ThemeColor(Color::Reset),
+            selected_header_fg: ThemeColor(Color::White),
+            selected_header_bg: ThemeColor(Color::DarkGray),
+            row_highlight_fg: ThemeColor(Color::White),
+            row_highlight_bg: ThemeColor(Color::DarkGray),
+            marked_row_fg: ThemeColor(Color::Yellow),
+            filter_prefix_fg: ThemeColor(Color::Red),
+            filter_error_fg: ThemeColor(Color::LightRed),
+            status_colors: StatusColors::default(),
+            priority_colors: PriorityColors::default(),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use std::path::Path;
 use std::time::Duration;
 
 use clap::Parser;
@@ -5,7 +6,11 @@ use dotenvy::dotenv;
 use error_stack::{Report, ResultExt};
 use intrack::{
     common::report::{Missing, Suggestion},
-    feat::{cli::CliArgs, issues::Issues, tui::TuiState},
+    feat::{
+        cli::{CliArgs, Command},
+        issues::{Issues, IssuesEventError, Severity, lint_events},
+        tui::TuiState,
+    },
     App, AppConfig, AppError, AppNewArgs,
 };
 
@@ -47,6 +52,16 @@ pub async fn main() -> Result<(), Report<AppError>> {
         .change_context(AppError)
         .attach("failed to create event log")?;
 
+    match args.command {
+        Some(Command::Doctor { fix }) => {
+            return run_doctor(&args.event_log, fix).change_context(AppError);
+        }
+        Some(Command::Compact) => {
+            return run_compact(&args.event_log).change_context(AppError);
+        }
+        None => {}
+    }
+
     let args = AppNewArgs::builder()
         .issues(
             Issues::from_jsonl_file(&args.event_log)
@@ -61,3 +76,71 @@ pub async fn main() -> Result<(), Report<AppError>> {
 
     Ok(())
 }
+
+/// Runs `intrack doctor`: checks the event log at `event_log`'s tamper-evident hash chain (see
+/// `feat::issues::verify_jsonl_file`), then lints it for logically-invalid events (see
+/// `feat::issues::lint_events`) and reports them. With `fix`, rewrites the log with each
+/// diagnostic's suggested repair applied instead of just reporting it.
+///
+/// A broken hash chain is reported but doesn't stop the lint pass (or `fix`) from running --
+/// there's nothing `apply_fixes` can repair about it (the chain records truncation, reordering,
+/// or a manual edit, not a logically-invalid event), so it's surfaced for the user to investigate
+/// rather than treated as fatal here.
+///
+/// # Errors
+///
+/// Returns an error if the event log cannot be read, or if `fix` is set and the cleaned log
+/// cannot be written.
+fn run_doctor(event_log: &Path, fix: bool) -> Result<(), Report<IssuesEventError>> {
+    match Issues::verify_jsonl_file(event_log) {
+        Ok(()) => println!("hash chain ok for {}", event_log.display()),
+        Err(report) => println!(
+            "hash chain verification failed for {}:\n{report:?}",
+            event_log.display()
+        ),
+    }
+
+    let events = Issues::read_events(event_log)?;
+    let diagnostics = lint_events(&events);
+
+    if diagnostics.is_empty() {
+        println!("no problems found in {}", event_log.display());
+        return Ok(());
+    }
+
+    for diagnostic in &diagnostics {
+        let severity = match diagnostic.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        println!(
+            "{severity} at line {}: {}",
+            diagnostic.line, diagnostic.message
+        );
+    }
+
+    if fix {
+        Issues::apply_fixes(&events, &diagnostics, event_log)?;
+        println!("applied fixes for {} diagnostic(s)", diagnostics.len());
+    } else {
+        println!(
+            "{} diagnostic(s) found; re-run with --fix to repair",
+            diagnostics.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// Runs `intrack compact`: folds the event log at `event_log` into its minimal equivalent
+/// stream (see `feat::issues::compact_events`) and rewrites it in place, on demand rather than
+/// waiting for `App::append_event`'s periodic snapshot.
+///
+/// # Errors
+///
+/// Returns an error if the log cannot be read, or the rewritten log cannot be written.
+fn run_compact(event_log: &Path) -> Result<(), Report<IssuesEventError>> {
+    Issues::compact_jsonl_file(event_log)?;
+    println!("compacted {}", event_log.display());
+    Ok(())
+}
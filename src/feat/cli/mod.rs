@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use clap_verbosity_flag::{Verbosity, WarnLevel};
 
 #[derive(Parser, Debug)]
@@ -10,6 +10,31 @@ pub struct CliArgs {
     #[arg(short = 'f', long = "file", default_value = "issues.jsonl")]
     pub event_log: PathBuf,
 
+    /// Path to the git repository scanned for issue-referencing commits.
+    #[arg(long = "repo", default_value = ".")]
+    pub repo: PathBuf,
+
     #[command(flatten)]
     pub verbosity: Verbosity<WarnLevel>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// A one-shot diagnostic/maintenance operation, run instead of launching the TUI.
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Check the event log for logically-invalid events (see `feat::issues::lint_events`) and
+    /// report them, optionally rewriting the log with the suggested fixes applied.
+    Doctor {
+        /// Rewrite the event log with each diagnostic's suggested fix applied, instead of just
+        /// reporting what's wrong.
+        #[arg(long)]
+        fix: bool,
+    },
+    /// Fold the event log's full history into its minimal equivalent stream (see
+    /// `feat::issues::compact_events`), shrinking on-disk size and future replay time without
+    /// changing the projected state. Runs on top of whatever `Issues::write_snapshot` already
+    /// does periodically during normal use; this is the "do it now" counterpart.
+    Compact,
 }
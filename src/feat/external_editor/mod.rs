@@ -7,6 +7,33 @@ use wherror::Error;
 #[error(debug)]
 pub struct ExternalEditorError;
 
+/// Resolves which editor command to launch for external-editor requests.
+///
+/// Resolution order: `config_editor` (the `editor` value from `AppConfig`) → `$VISUAL` →
+/// `$EDITOR` → a platform default (`notepad` on Windows, `vi` everywhere else). Terminal
+/// users should always land in their terminal editor rather than whatever GUI program is
+/// registered for `.txt`.
+pub fn resolve_editor_command(config_editor: Option<&str>) -> String {
+    if let Some(editor) = config_editor {
+        return editor.to_string();
+    }
+    if let Ok(visual) = std::env::var("VISUAL")
+        && !visual.is_empty()
+    {
+        return visual;
+    }
+    if let Ok(editor) = std::env::var("EDITOR")
+        && !editor.is_empty()
+    {
+        return editor;
+    }
+    if cfg!(windows) {
+        "notepad".to_string()
+    } else {
+        "vi".to_string()
+    }
+}
+
 pub type ExternalEditorCallback =
     Box<dyn FnOnce(&mut App, Option<String>) -> Result<(), Report<ExternalEditorError>>>;
 
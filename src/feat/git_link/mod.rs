@@ -0,0 +1,132 @@
+mod reference;
+
+use std::path::Path;
+
+use error_stack::{Report, ResultExt};
+use jiff::Timestamp;
+use wherror::Error;
+
+use reference::parse_references;
+
+use crate::feat::{
+    issue::{Comment, LinkedCommit, Status},
+    issues::IssueEvent,
+};
+
+/// Error type for git history scanning operations.
+///
+/// This error is returned when the repository cannot be opened or its commit history cannot
+/// be walked, such as when the configured repo path isn't a git repository or `HEAD` is
+/// unborn.
+#[derive(Debug, Error)]
+#[error(debug)]
+pub struct GitLinkError;
+
+/// Walks commit history from `HEAD` back to (but excluding) `since_oid`, turning commit-message
+/// references into `IssueEvent`s: one `CommitLinked` per `#<id>` reference found, plus a
+/// `StatusChanged`/`CommentAdded` pair for each reference that carries a closing keyword
+/// (`Closes`, `Fixes`, `Resolves`). A trailing `GitScanned` event records the new `HEAD` oid so
+/// the next call can resume from here instead of re-walking history already linked.
+///
+/// Returns an empty list without opening the repository's history if `HEAD` already is
+/// `since_oid`.
+///
+/// # Errors
+///
+/// Returns an error if the repository at `repo_path` cannot be opened, `HEAD` cannot be
+/// resolved, or a commit encountered while walking history cannot be read.
+pub fn scan<P>(
+    repo_path: P,
+    since_oid: Option<&str>,
+) -> Result<Vec<IssueEvent>, Report<GitLinkError>>
+where
+    P: AsRef<Path>,
+{
+    let repo_path = repo_path.as_ref();
+    let repo = gix::open(repo_path)
+        .change_context(GitLinkError)
+        .attach_with(|| format!("failed to open git repository at {}", repo_path.display()))?;
+
+    let head_id = repo
+        .head_id()
+        .change_context(GitLinkError)
+        .attach("failed to resolve HEAD")?;
+    let head_oid = head_id.to_string();
+
+    if since_oid == Some(head_oid.as_str()) {
+        return Ok(Vec::new());
+    }
+
+    let walk = head_id
+        .ancestors()
+        .all()
+        .change_context(GitLinkError)
+        .attach("failed to walk commit history from HEAD")?;
+
+    let mut events = Vec::new();
+    for info in walk {
+        let info = info
+            .change_context(GitLinkError)
+            .attach("failed to read a commit while walking history")?;
+
+        let oid = info.id.to_string();
+        if since_oid == Some(oid.as_str()) {
+            break;
+        }
+
+        let commit = info
+            .object()
+            .change_context(GitLinkError)
+            .attach_with(|| format!("failed to read commit object {oid}"))?
+            .into_commit();
+        let message = commit.message_raw_sloppy().to_string();
+        let summary = message.lines().next().unwrap_or_default().to_string();
+        let author = commit
+            .author()
+            .change_context(GitLinkError)
+            .attach_with(|| format!("failed to read author of commit {oid}"))?
+            .name
+            .to_string();
+        let time = commit
+            .time()
+            .ok()
+            .and_then(|time| Timestamp::from_second(time.seconds).ok())
+            .unwrap_or(Timestamp::UNIX_EPOCH);
+        let short_oid: String = oid.chars().take(8).collect();
+
+        for reference in parse_references(&message) {
+            events.push(IssueEvent::CommitLinked {
+                issue_id: reference.issue_id,
+                commit: LinkedCommit {
+                    oid: short_oid.clone(),
+                    summary: summary.clone(),
+                    author: author.clone(),
+                    time,
+                    closes: reference.closes,
+                },
+            });
+
+            if reference.closes {
+                events.push(IssueEvent::StatusChanged {
+                    issue_id: reference.issue_id,
+                    status: Status::closed(),
+                });
+                events.push(IssueEvent::CommentAdded(Comment {
+                    parent_issue: reference.issue_id,
+                    content: format!("Closed by commit {short_oid}: {summary}"),
+                    created: time,
+                    created_by: author.clone(),
+                }));
+            }
+        }
+    }
+
+    // `ancestors()` walks newest-first; replay oldest-first so status changes and comments land
+    // in the order the commits actually happened.
+    events.reverse();
+    events.push(IssueEvent::GitScanned {
+        up_to_oid: head_oid,
+    });
+
+    Ok(events)
+}
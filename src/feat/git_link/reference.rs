@@ -0,0 +1,91 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::feat::issue::IssueId;
+
+/// Matches a `#<id>` reference, optionally preceded by a closing keyword (`Closes`/`Closed`,
+/// `Fix`/`Fixes`/`Fixed`, `Resolve`/`Resolves`/`Resolved`) with only whitespace or a colon
+/// between the keyword and the `#`. Bare references (including ones prefixed with `Refs`/
+/// `References`, which aren't captured as an `action`) still match, just without `closes` set.
+static RE_REFERENCE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?:(?P<action>close[sd]?|fix(?:e[sd])?|resolve[sd]?)\s*:?\s*)?#(?P<id>\d+)\b")
+        .expect("reference regex is valid")
+});
+
+/// One `#<id>` reference extracted from a commit message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct CommitReference {
+    pub issue_id: IssueId,
+    pub closes: bool,
+}
+
+/// Extracts every issue reference from a commit message, in the order they appear.
+pub(super) fn parse_references(message: &str) -> Vec<CommitReference> {
+    RE_REFERENCE
+        .captures_iter(message)
+        .filter_map(|caps| {
+            let issue_id: IssueId = caps.name("id")?.as_str().parse().ok()?;
+            let closes = caps.name("action").is_some();
+            Some(CommitReference { issue_id, closes })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bare_reference_does_not_close() {
+        let refs = parse_references("see #3 for context");
+        assert_eq!(refs, vec![CommitReference { issue_id: 3, closes: false }]);
+    }
+
+    #[test]
+    fn closes_keyword_closes() {
+        for keyword in ["Closes", "Close", "Closed", "closes"] {
+            let refs = parse_references(&format!("{keyword} #12"));
+            assert_eq!(refs, vec![CommitReference { issue_id: 12, closes: true }]);
+        }
+    }
+
+    #[test]
+    fn fixes_and_resolves_keywords_close() {
+        let refs = parse_references("Fixes #7");
+        assert_eq!(refs, vec![CommitReference { issue_id: 7, closes: true }]);
+
+        let refs = parse_references("Resolves #9");
+        assert_eq!(refs, vec![CommitReference { issue_id: 9, closes: true }]);
+    }
+
+    #[test]
+    fn refs_keyword_links_without_closing() {
+        let refs = parse_references("Refs #7");
+        assert_eq!(refs, vec![CommitReference { issue_id: 7, closes: false }]);
+    }
+
+    #[test]
+    fn keyword_far_from_reference_does_not_close() {
+        let refs = parse_references("fixes a regression, see #3 also");
+        assert_eq!(refs, vec![CommitReference { issue_id: 3, closes: false }]);
+    }
+
+    #[test]
+    fn multiple_references_in_one_message() {
+        let refs = parse_references("Closes #1, refs #2 and fixes #3");
+        assert_eq!(
+            refs,
+            vec![
+                CommitReference { issue_id: 1, closes: true },
+                CommitReference { issue_id: 2, closes: false },
+                CommitReference { issue_id: 3, closes: true },
+            ]
+        );
+    }
+
+    #[test]
+    fn no_references() {
+        assert!(parse_references("just a regular commit message").is_empty());
+    }
+}
@@ -0,0 +1,104 @@
+use std::{
+    collections::HashMap,
+    process::{Command, Stdio},
+};
+
+use serde::Deserialize;
+
+use crate::feat::issues::IssueEvent;
+
+/// The `IssueEvent` kinds a hook can be bound to.
+///
+/// Narrower than the full `IssueEvent` enum: only the transitions a hook command plausibly
+/// wants to react to are exposed. Git-sync bookkeeping events (`CommitLinked`, `GitScanned`)
+/// and priority bumps don't fire hooks.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Deserialize)]
+pub enum HookEvent {
+    IssueCreated,
+    StatusChanged,
+    CommentAdded,
+}
+
+/// User-configured shell commands to run when an `IssueEvent` of a given kind is recorded.
+///
+/// Each command is a shell template (run via `sh -c`) with the event's fields exported as
+/// `INTRACK_*` environment variables. See `HookConfig::fire`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct HookConfig {
+    bindings: HashMap<HookEvent, Vec<String>>,
+}
+
+impl HookConfig {
+    /// Runs every command bound to `event`'s kind, exporting its fields as `INTRACK_*`
+    /// environment variables. Commands are spawned, not waited on, so a slow or hanging hook
+    /// can't block the TUI; a hook's own exit status is therefore never observed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the message of the last command in the batch that failed to even start (e.g.
+    /// the shell itself couldn't be launched). This is meant for a single status-line warning,
+    /// not per-command reporting, so earlier failures in the same batch aren't individually
+    /// surfaced.
+    pub fn fire(&self, event: &IssueEvent) -> Result<(), String> {
+        let Some((kind, env)) = hook_env(event) else {
+            return Ok(());
+        };
+        let Some(commands) = self.bindings.get(&kind) else {
+            return Ok(());
+        };
+
+        let mut last_error = None;
+        for template in commands {
+            if let Err(err) = spawn_hook(template, &env) {
+                last_error = Some(format!("hook `{template}` failed to start: {err}"));
+            }
+        }
+        last_error.map_or(Ok(()), Err)
+    }
+}
+
+/// Maps an `IssueEvent` to its `HookEvent` kind and the `INTRACK_*` environment variables
+/// describing it, or `None` for event kinds hooks don't fire on.
+fn hook_env(event: &IssueEvent) -> Option<(HookEvent, Vec<(&'static str, String)>)> {
+    match event {
+        IssueEvent::IssueCreated(issue) => Some((
+            HookEvent::IssueCreated,
+            vec![
+                ("INTRACK_ISSUE_ID", issue.id.to_string()),
+                ("INTRACK_TITLE", issue.title.clone()),
+            ],
+        )),
+        IssueEvent::StatusChanged { issue_id, status } => Some((
+            HookEvent::StatusChanged,
+            vec![
+                ("INTRACK_ISSUE_ID", issue_id.to_string()),
+                ("INTRACK_NEW_STATUS", status.to_string()),
+            ],
+        )),
+        IssueEvent::CommentAdded(comment) => Some((
+            HookEvent::CommentAdded,
+            vec![("INTRACK_ISSUE_ID", comment.parent_issue.to_string())],
+        )),
+        IssueEvent::PriorityChanged { .. }
+        | IssueEvent::CommitLinked { .. }
+        | IssueEvent::GitScanned { .. }
+        | IssueEvent::IssueDeleted { .. } => None,
+    }
+}
+
+/// Spawns `template` through the shell with `env` exported, without waiting for it to finish.
+fn spawn_hook(template: &str, env: &[(&'static str, String)]) -> std::io::Result<()> {
+    let mut command = Command::new("sh");
+    command
+        .arg("-c")
+        .arg(template)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    for (key, value) in env {
+        command.env(key, value);
+    }
+    command.spawn()?;
+    Ok(())
+}
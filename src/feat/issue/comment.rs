@@ -7,6 +7,6 @@ use crate::feat::issue::IssueId;
 pub struct Comment {
     pub parent_issue: IssueId,
     pub content: String,
-    pub created_at: Timestamp,
-    pub author: String,
+    pub created: Timestamp,
+    pub created_by: String,
 }
@@ -0,0 +1,15 @@
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+
+/// A commit whose message referenced an issue, recorded by `feat::git_link`'s history scan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkedCommit {
+    /// Abbreviated commit hash.
+    pub oid: String,
+    /// First line of the commit message.
+    pub summary: String,
+    pub author: String,
+    pub time: Timestamp,
+    /// Whether this reference carried a closing keyword (`Closes`/`Fixes`/`Resolves`).
+    pub closes: bool,
+}
@@ -6,13 +6,17 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 
 mod comment;
+mod linked_commit;
 mod priority;
 mod status;
 mod template;
 
 pub use comment::Comment;
+pub use linked_commit::LinkedCommit;
 pub use priority::{Priority, PriorityParseError};
-pub use status::{Status, StatusParseError};
+pub use status::{
+    Status, StatusDef, StatusParseError, StatusSet, set_active as set_active_status_set,
+};
 pub use template::IssueItemTemplate;
 use wherror::Error;
 
@@ -95,7 +99,7 @@ custom:
                 id: new_id,
                 title: issue.title,
                 created: Timestamp::now(),
-                status: Status::Open,
+                status: Status::open(),
                 priority: issue.priority,
                 created_by: issue.created_by,
                 custom: issue.custom,
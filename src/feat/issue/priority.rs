@@ -1,7 +1,7 @@
 use std::str::FromStr;
 
 use error_stack::{Report, ResultExt};
-use serde::{de::Visitor, Deserialize, Deserializer, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, de::Visitor};
 use strum::Display;
 use wherror::Error;
 
@@ -26,6 +26,21 @@ pub enum Priority {
 #[error(debug)]
 pub struct PriorityParseError;
 
+impl Priority {
+    /// The next priority up in severity, saturating at `Blocker`. Used by the issue table's bulk
+    /// "bump priority" action (`p`), where there's no sensible wrap-back-to-`Trivial` the way
+    /// `Status::cycle_next` wraps through workflow states.
+    pub fn bump(&self) -> Self {
+        match self {
+            Priority::Trivial => Priority::Low,
+            Priority::Low => Priority::Medium,
+            Priority::Medium => Priority::High,
+            Priority::High => Priority::Critical,
+            Priority::Critical | Priority::Blocker => Priority::Blocker,
+        }
+    }
+}
+
 impl FromStr for Priority {
     type Err = Report<PriorityParseError>;
 
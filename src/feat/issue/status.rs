@@ -1,34 +1,173 @@
-use std::str::FromStr;
+use std::{
+    fmt,
+    str::FromStr,
+    sync::{LazyLock, PoisonError, RwLock},
+};
 
 use error_stack::{Report, ResultExt};
-use serde::{de::Visitor, Deserialize, Deserializer, Serialize};
-use strum::Display;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Visitor};
 use wherror::Error;
 
 #[derive(Debug, Error)]
 #[error(debug)]
 pub struct StatusParseError;
 
-#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord, Display, Serialize)]
-pub enum Status {
-    /// Issue is open.
-    #[default]
-    Open,
-    /// Issue is closed.
-    Closed,
+/// One workflow state in a [`StatusSet`]: a display name plus the strings that parse into it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusDef {
+    pub name: String,
+    /// Case-insensitive strings (in addition to `name` itself) that parse into this state.
+    pub aliases: Vec<String>,
+    /// Whether this state counts as "closed" for callers like `Status::closed` that need a
+    /// single terminal state (e.g. the git-history auto-closer).
+    #[serde(default)]
+    pub terminal: bool,
+}
+
+/// An ordered, user-configurable list of workflow states issues can be in.
+///
+/// Replaces a fixed `Open`/`Closed` enum so teams can add states like `InProgress` or `Blocked`.
+/// A [`Status`] is just an index into whichever `StatusSet` is currently active (see
+/// [`set_active`]), so its ordering, parsing, and display name all resolve through that set.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusSet(Vec<StatusDef>);
+
+impl StatusSet {
+    pub fn states(&self) -> &[StatusDef] {
+        &self.0
+    }
+
+    fn parse(&self, s: &str) -> Option<Status> {
+        self.0
+            .iter()
+            .position(|def| {
+                def.name.eq_ignore_ascii_case(s)
+                    || def.aliases.iter().any(|a| a.eq_ignore_ascii_case(s))
+            })
+            .map(Status)
+    }
+
+    fn name(&self, status: Status) -> &str {
+        self.0
+            .get(status.0)
+            .map_or("unknown", |def| def.name.as_str())
+    }
+
+    fn terminal(&self) -> Option<Status> {
+        self.0.iter().position(|def| def.terminal).map(Status)
+    }
+}
+
+impl Default for StatusSet {
+    /// The built-in `Open`/`Closed` set, with the aliases this tracker has always accepted, so
+    /// existing JSONL event logs that only ever recorded those two states still deserialize.
+    fn default() -> Self {
+        StatusSet(vec![
+            StatusDef {
+                name: "Open".to_string(),
+                aliases: vec!["active".to_string(), "pending".to_string()],
+                terminal: false,
+            },
+            StatusDef {
+                name: "Closed".to_string(),
+                aliases: vec!["done".to_string(), "finished".to_string()],
+                terminal: true,
+            },
+        ])
+    }
+}
+
+/// The `StatusSet` that [`Status`] values are parsed, ordered, and displayed against.
+///
+/// Set once at startup from `AppConfig::status_set` (see `App::new`). A process-wide static
+/// (rather than threading a `&StatusSet` through every `Status`-touching call site, most of
+/// which predate this type and have no config in scope) keeps the change minimal; it defaults
+/// to the built-in set so code that runs before config is loaded, and tests, still work.
+static ACTIVE_STATUS_SET: LazyLock<RwLock<StatusSet>> =
+    LazyLock::new(|| RwLock::new(StatusSet::default()));
+
+/// Installs `set` as the active [`StatusSet`] that all `Status` values resolve against.
+pub fn set_active(set: StatusSet) {
+    *ACTIVE_STATUS_SET
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = set;
+}
+
+fn with_active<T>(f: impl FnOnce(&StatusSet) -> T) -> T {
+    f(&ACTIVE_STATUS_SET
+        .read()
+        .unwrap_or_else(PoisonError::into_inner))
+}
+
+/// A workflow status: an interned index into the currently-active [`StatusSet`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub struct Status(usize);
+
+impl Status {
+    /// The first configured state, used as the status of a newly-created issue.
+    pub fn open() -> Self {
+        Status(0)
+    }
+
+    /// The configured state marked `terminal`, falling back to the last configured state if none
+    /// is marked. Used by callers (e.g. the git-history auto-closer) that just need "the closed
+    /// state" without naming it.
+    pub fn closed() -> Self {
+        with_active(|set| {
+            set.terminal()
+                .unwrap_or_else(|| Status(set.states().len().saturating_sub(1)))
+        })
+    }
+
+    /// This status's display name in the currently-active `StatusSet`.
+    pub fn name(&self) -> String {
+        with_active(|set| set.name(*self).to_string())
+    }
+
+    /// The next status in the currently-active `StatusSet`'s configured order, wrapping back to
+    /// the first state after the last. Used by the `s` (toggle status) keybinding: with only the
+    /// built-in `Open`/`Closed` set this is the same binary flip it always was, but it also
+    /// cycles sensibly through a longer configured workflow like `Open` -> `InProgress` ->
+    /// `Closed`.
+    pub fn cycle_next(&self) -> Self {
+        with_active(|set| Status((self.0 + 1) % set.states().len().max(1)))
+    }
+
+    /// Every status in the currently-active `StatusSet`, in its configured order. Used by the
+    /// kanban board view (`feat::tui_board`) to lay out one column per status.
+    pub fn all() -> Vec<Self> {
+        with_active(|set| (0..set.states().len()).map(Status).collect())
+    }
+}
+
+impl Default for Status {
+    fn default() -> Self {
+        Status::open()
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name())
+    }
 }
 
 impl FromStr for Status {
     type Err = Report<StatusParseError>;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
-            "open" | "active" | "pending" => Ok(Status::Open),
-            "closed" | "done" | "finished" => Ok(Status::Closed),
-            other => {
-                Err(StatusParseError).attach_with(|| format!("cannot parse '{other}' into Status"))
-            }
-        }
+        with_active(|set| set.parse(s))
+            .ok_or(StatusParseError)
+            .attach_with(|| format!("cannot parse '{s}' into Status"))
+    }
+}
+
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.name())
     }
 }
 
@@ -43,8 +182,7 @@ impl<'de> Deserialize<'de> for Status {
             type Value = Status;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                formatter
-                    .write_str(r#""open", "active", "pending", "closed", "done", or "finished""#)
+                formatter.write_str("a name or alias from the active status set")
             }
 
             fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
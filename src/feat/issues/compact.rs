@@ -0,0 +1,49 @@
+use std::collections::HashSet;
+
+use super::{IssueEvent, Issues};
+
+/// Folds `events` into their minimal equivalent stream, preserving the same final projected
+/// state (see `Issues::apply_event`) while dropping everything only useful for getting there:
+///
+/// - `StatusChanged`/`PriorityChanged` are dropped entirely; the issue's latest status and
+///   priority are folded straight into its `IssueCreated` (which already carries both fields).
+/// - `CommentAdded`/`CommitLinked` referencing an issue that no longer exists (it was since
+///   deleted) are dropped, since nothing can ever resolve that reference again.
+/// - `IssueDeleted`, and the `IssueCreated` it tombstones, are both dropped — the deletion and
+///   its target cancel out.
+/// - `CommentAdded`/`CommitLinked`/`GitScanned` that still apply pass through unchanged.
+///
+/// Used by `Issues::compact_jsonl_file` to keep the on-disk log, and future replay time, from
+/// growing without bound.
+pub fn compact_events(events: &[IssueEvent]) -> Vec<IssueEvent> {
+    let final_state = Issues::from_events(events.iter().cloned());
+    let mut already_created = HashSet::new();
+    let mut output = Vec::new();
+
+    for event in events {
+        match event {
+            IssueEvent::IssueCreated(issue) => {
+                if let Some(current) = final_state.get_issue(&issue.id) {
+                    if already_created.insert(issue.id) {
+                        output.push(IssueEvent::IssueCreated(current.clone()));
+                    }
+                }
+            }
+            IssueEvent::StatusChanged { .. } | IssueEvent::PriorityChanged { .. } => {}
+            IssueEvent::CommentAdded(comment) => {
+                if final_state.get_issue(&comment.parent_issue).is_some() {
+                    output.push(event.clone());
+                }
+            }
+            IssueEvent::CommitLinked { issue_id, .. } => {
+                if final_state.get_issue(issue_id).is_some() {
+                    output.push(event.clone());
+                }
+            }
+            IssueEvent::GitScanned { .. } => output.push(event.clone()),
+            IssueEvent::IssueDeleted { .. } => {}
+        }
+    }
+
+    output
+}
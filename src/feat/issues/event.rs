@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::feat::issue::{Comment, Issue, IssueId, Priority, Status};
+use crate::feat::issue::{Comment, Issue, IssueId, LinkedCommit, Priority, Status};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum IssueEvent {
@@ -14,6 +14,22 @@ pub enum IssueEvent {
         issue_id: IssueId,
         priority: Priority,
     },
+    /// A commit whose message referenced `issue_id` was found by `feat::git_link::scan`.
+    CommitLinked {
+        issue_id: IssueId,
+        commit: LinkedCommit,
+    },
+    /// Records how far `feat::git_link::scan` has walked commit history, so the next scan can
+    /// resume from this oid instead of re-walking commits already linked.
+    GitScanned {
+        up_to_oid: String,
+    },
+    /// A tombstone for `issue_id`, removing it from the projected state. The log itself is
+    /// append-only, so this is how undoing an `IssueCreated` is represented; see
+    /// `Issues::invert_event`.
+    IssueDeleted {
+        issue_id: IssueId,
+    },
 }
 
 impl From<Issue> for IssueEvent {
@@ -0,0 +1,214 @@
+use std::collections::{HashMap, HashSet};
+
+use jiff::Timestamp;
+
+use crate::feat::issue::{Issue, IssueId, Priority, Status};
+
+use super::IssueEvent;
+
+/// How serious a [`Diagnostic`] is. Nothing currently distinguishes the two beyond display, but
+/// keeping them separate leaves room for `intrack doctor` to e.g. exit non-zero only on `Error`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// Logically inconsistent but harmless to leave as-is. [`lint_events`] raises none of these
+    /// at present; deleting an issue that was never created, for one, is not flagged at all.
+    Warning,
+    /// Logically inconsistent in a way that would otherwise corrupt the projected state (e.g. a
+    /// comment or status change referencing an issue that doesn't exist).
+    Error,
+}
+
+/// A concrete repair [`lint_events`] suggests for a [`Diagnostic`], to be carried out by
+/// [`apply_fixes`].
+#[derive(Debug, Clone)]
+pub enum Fix {
+    /// Drop the offending event when rewriting the log.
+    DropEvent,
+    /// Insert a placeholder `IssueCreated` immediately before the offending event, so the
+    /// reference it makes becomes valid instead of being thrown away.
+    SynthesizeIssue(Issue),
+}
+
+/// One logically-invalid event found by [`lint_events`].
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    /// 1-based position of the offending event within the slice passed to [`lint_events`]. This
+    /// is the event's index, not necessarily the event log's file line number — blank lines are
+    /// skipped before events ever reach this point (see `Issues::read_events`).
+    pub line: usize,
+    pub severity: Severity,
+    pub message: String,
+    /// A suggested repair, if one is possible. `None` never occurs today, since every diagnostic
+    /// this module raises has a known fix, but callers shouldn't assume that stays true forever.
+    pub fix: Option<Fix>,
+}
+
+/// Replays `events` purely to find ones that are logically invalid against the ones before
+/// them — a `CommentAdded`, `StatusChanged`, or `PriorityChanged` referencing an `IssueId` no
+/// `IssueCreated` defined, or a duplicate `IssueCreated` for an ID already created — and reports
+/// each as a [`Diagnostic`] paired with a suggested [`Fix`].
+///
+/// Unlike `Issues::apply_event`, this never mutates any projected state; it's meant to run
+/// *before* `Issues::from_events` on a log that was hand-edited or merged via git, so problems
+/// surface as an explicit report instead of silently producing a half-applied projection.
+pub fn lint_events(events: &[IssueEvent]) -> Vec<Diagnostic> {
+    let mut known_ids: HashSet<IssueId> = HashSet::new();
+    let mut diagnostics = Vec::new();
+
+    for (idx, event) in events.iter().enumerate() {
+        let line = idx + 1;
+        match event {
+            IssueEvent::IssueCreated(issue) => {
+                if !known_ids.insert(issue.id) {
+                    diagnostics.push(Diagnostic {
+                        line,
+                        severity: Severity::Error,
+                        message: format!("duplicate IssueCreated for issue #{}", issue.id),
+                        fix: Some(Fix::DropEvent),
+                    });
+                }
+            }
+            IssueEvent::CommentAdded(comment) => {
+                if !known_ids.contains(&comment.parent_issue) {
+                    diagnostics.push(Diagnostic {
+                        line,
+                        severity: Severity::Error,
+                        message: format!(
+                            "comment added to issue #{} which was never created",
+                            comment.parent_issue
+                        ),
+                        fix: Some(Fix::DropEvent),
+                    });
+                }
+            }
+            IssueEvent::StatusChanged { issue_id, .. } => {
+                if !known_ids.contains(issue_id) {
+                    diagnostics.push(Diagnostic {
+                        line,
+                        severity: Severity::Error,
+                        message: format!(
+                            "status changed on issue #{issue_id} which was never created"
+                        ),
+                        fix: Some(Fix::SynthesizeIssue(placeholder_issue(*issue_id))),
+                    });
+                }
+            }
+            IssueEvent::PriorityChanged { issue_id, .. } => {
+                if !known_ids.contains(issue_id) {
+                    diagnostics.push(Diagnostic {
+                        line,
+                        severity: Severity::Error,
+                        message: format!(
+                            "priority changed on issue #{issue_id} which was never created"
+                        ),
+                        fix: Some(Fix::SynthesizeIssue(placeholder_issue(*issue_id))),
+                    });
+                }
+            }
+            IssueEvent::IssueDeleted { issue_id } => {
+                known_ids.remove(issue_id);
+            }
+            IssueEvent::CommitLinked { .. } | IssueEvent::GitScanned { .. } => {}
+        }
+    }
+
+    diagnostics
+}
+
+/// Builds a placeholder `Issue` for [`Fix::SynthesizeIssue`]: an obviously-synthetic title,
+/// lowest priority, default status, and an epoch creation timestamp, so it's easy to spot in the
+/// issue table and isn't mistaken for a real issue's history.
+fn placeholder_issue(issue_id: IssueId) -> Issue {
+    Issue {
+        id: issue_id,
+        title: format!("[recovered by intrack doctor] issue #{issue_id}"),
+        created: Timestamp::UNIX_EPOCH,
+        status: Status::default(),
+        priority: Priority::default(),
+        created_by: String::from("intrack-doctor"),
+        custom: HashMap::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feat::issue::Comment;
+
+    fn issue(id: IssueId) -> Issue {
+        Issue {
+            id,
+            title: String::from("title"),
+            created: Timestamp::UNIX_EPOCH,
+            status: Status::default(),
+            priority: Priority::default(),
+            created_by: String::from("tester"),
+            custom: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn clean_log_has_no_diagnostics() {
+        let events = vec![
+            IssueEvent::IssueCreated(issue(1)),
+            IssueEvent::CommentAdded(Comment {
+                parent_issue: 1,
+                content: String::from("hi"),
+                created: Timestamp::UNIX_EPOCH,
+                created_by: String::from("tester"),
+            }),
+            IssueEvent::StatusChanged {
+                issue_id: 1,
+                status: Status::default(),
+            },
+        ];
+        assert!(lint_events(&events).is_empty());
+    }
+
+    #[test]
+    fn comment_on_missing_issue_is_flagged_with_drop_fix() {
+        let events = vec![IssueEvent::CommentAdded(Comment {
+            parent_issue: 42,
+            content: String::from("orphan"),
+            created: Timestamp::UNIX_EPOCH,
+            created_by: String::from("tester"),
+        })];
+        let diagnostics = lint_events(&events);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line, 1);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert!(matches!(diagnostics[0].fix, Some(Fix::DropEvent)));
+    }
+
+    #[test]
+    fn status_change_on_missing_issue_is_flagged_with_synthesize_fix() {
+        let events = vec![IssueEvent::StatusChanged {
+            issue_id: 7,
+            status: Status::default(),
+        }];
+        let diagnostics = lint_events(&events);
+        assert_eq!(diagnostics.len(), 1);
+        match &diagnostics[0].fix {
+            Some(Fix::SynthesizeIssue(issue)) => assert_eq!(issue.id, 7),
+            other => panic!("expected SynthesizeIssue fix, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn duplicate_issue_created_is_flagged() {
+        let events = vec![
+            IssueEvent::IssueCreated(issue(1)),
+            IssueEvent::IssueCreated(issue(1)),
+        ];
+        let diagnostics = lint_events(&events);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line, 2);
+        assert!(matches!(diagnostics[0].fix, Some(Fix::DropEvent)));
+    }
+
+    #[test]
+    fn deleting_unknown_issue_is_not_flagged() {
+        let events = vec![IssueEvent::IssueDeleted { issue_id: 99 }];
+        assert!(lint_events(&events).is_empty());
+    }
+}
@@ -1,14 +1,24 @@
-use std::{collections::HashMap, fs::OpenOptions, io::Write, path::Path};
+use std::{
+    collections::{HashMap, HashSet},
+    fs::OpenOptions,
+    io::Write,
+    path::{Path, PathBuf},
+};
 
 use error_stack::{Report, ResultExt};
 use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
 use wherror::Error;
 
-use crate::feat::issue::{Comment, Issue, IssueId};
+use crate::feat::issue::{Comment, Issue, IssueId, LinkedCommit};
 
+mod compact;
 mod event;
+mod lint;
 
+pub use compact::compact_events;
 pub use event::IssueEvent;
+pub use lint::{Diagnostic, Fix, Severity, lint_events};
 
 /// Error type for issues-related event operations.
 ///
@@ -18,6 +28,91 @@ pub use event::IssueEvent;
 #[error(debug)]
 pub struct IssuesEventError;
 
+/// Tip hash a fresh or entirely-legacy event log chains from, i.e. the `prev` of the first
+/// chained record ever appended to a given log.
+const GENESIS_HASH: u64 = 0;
+
+/// On-disk shape of a tamper-evident log line (see [`Issues::append_to_log`]). `hash` and `prev`
+/// are lowercase hex-encoded `u64`s; `event` is kept as raw JSON rather than a parsed `IssueEvent`
+/// so [`Issues::verify_jsonl_file`] can recompute `hash` from the exact bytes that were originally
+/// hashed, without depending on `serde_json` reserializing a parsed value identically.
+#[derive(Debug, Serialize, Deserialize)]
+struct ChainedRecord {
+    hash: String,
+    prev: String,
+    event: Box<RawValue>,
+}
+
+/// On-disk shape of a sidecar snapshot (see [`Issues::write_snapshot`]): the projected state plus
+/// the bookkeeping [`Issues::load_snapshot_then_tail`] needs to resume tailing the log from where
+/// the snapshot left off, none of which is part of `Issues`'s own (de)serialization.
+#[derive(Debug, Serialize, Deserialize)]
+struct Snapshot {
+    issues: Issues,
+    last_read_offset: u64,
+    last_read_line_count: usize,
+    tip_hash: u64,
+}
+
+/// Sidecar snapshot path for the event log at `path`: `path` with `.snapshot.json` appended.
+fn snapshot_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".snapshot.json");
+    PathBuf::from(name)
+}
+
+/// Computes the tamper-evident chain hash for a record appended after `prev`, over `prev`'s raw
+/// little-endian bytes followed by the event's serialized JSON bytes.
+fn chain_hash(prev: u64, event_json: &str) -> u64 {
+    let mut bytes = Vec::with_capacity(8 + event_json.len());
+    bytes.extend_from_slice(&prev.to_le_bytes());
+    bytes.extend_from_slice(event_json.as_bytes());
+    seahash::hash(&bytes)
+}
+
+/// A single parsed line of the event log: either a tamper-evident chained record (the current
+/// format, see [`Issues::append_to_log`]) or a bare event (the format written before the hash
+/// chain was introduced, kept readable for backward compatibility).
+enum LogLine {
+    Chained {
+        hash: u64,
+        prev: u64,
+        event_json: String,
+        event: IssueEvent,
+    },
+    Legacy(IssueEvent),
+}
+
+/// Parses a single non-empty, trimmed event-log line, detecting whether it's in the current
+/// chained format or the legacy bare-event format.
+fn parse_log_line(line: &str, line_no: usize) -> Result<LogLine, Report<IssuesEventError>> {
+    if let Ok(record) = serde_json::from_str::<ChainedRecord>(line) {
+        let hash = u64::from_str_radix(&record.hash, 16)
+            .change_context(IssuesEventError)
+            .attach_with(|| format!("invalid hash hex at line {line_no}"))?;
+        let prev = u64::from_str_radix(&record.prev, 16)
+            .change_context(IssuesEventError)
+            .attach_with(|| format!("invalid prev hash hex at line {line_no}"))?;
+        let event_json = record.event.get().to_string();
+        let event = serde_json::from_str::<IssueEvent>(&event_json)
+            .change_context(IssuesEventError)
+            .attach_with(|| format!("failed to deserialize event at line {line_no}"))
+            .attach_with(|| format!("content: {line}"))?;
+        return Ok(LogLine::Chained {
+            hash,
+            prev,
+            event_json,
+            event,
+        });
+    }
+
+    let event = serde_json::from_str::<IssueEvent>(line)
+        .change_context(IssuesEventError)
+        .attach_with(|| format!("failed to deserialize event at line {line_no}"))
+        .attach_with(|| format!("content: {line}"))?;
+    Ok(LogLine::Legacy(event))
+}
+
 /// A projected state representing all issues and their associated comments.
 ///
 /// This struct maintains a read-optimized view of the issues system by applying
@@ -27,6 +122,27 @@ pub struct IssuesEventError;
 pub struct Issues {
     issues: HashMap<IssueId, Issue>,
     comments: HashMap<IssueId, Vec<Comment>>,
+    linked_commits: HashMap<IssueId, Vec<LinkedCommit>>,
+    /// The HEAD oid `feat::git_link::scan` last walked up to, so the next scan only has to
+    /// cover new commits.
+    last_scanned_oid: Option<String>,
+
+    /// Byte offset into the event log up to which it's already been read, so
+    /// [`Self::reload_incremental`] only processes newly appended lines. Advanced by
+    /// `from_jsonl_file`, `append_to_log`, and `reload_incremental` itself. Not part of the
+    /// projected state, so it's excluded from (de)serialization.
+    #[serde(skip)]
+    last_read_offset: u64,
+    /// How many complete lines of the event log have been read so far, used only to report the
+    /// correct absolute line number in a [`Self::reload_incremental`] deserialization error.
+    #[serde(skip)]
+    last_read_line_count: usize,
+
+    /// Tip of the tamper-evident hash chain (see [`Self::append_to_log`]): the hash of the most
+    /// recently read or appended chained record, or [`GENESIS_HASH`] if none has been seen yet.
+    /// Not part of the projected state, so it's excluded from (de)serialization.
+    #[serde(skip)]
+    tip_hash: u64,
 }
 
 impl Issues {
@@ -37,6 +153,11 @@ impl Issues {
         self.issues.values()
     }
 
+    /// Looks up a single issue by id.
+    pub fn get_issue(&self, issue_id: &IssueId) -> Option<&Issue> {
+        self.issues.get(issue_id)
+    }
+
     /// Returns an iterator over all comments grouped by their parent issue.
     ///
     /// The iterator yields tuples of `(IssueId, &[Comment])` where each issue ID
@@ -45,6 +166,17 @@ impl Issues {
         self.comments.iter()
     }
 
+    /// Returns an iterator over all commits linked to issues by `feat::git_link::scan`, grouped
+    /// by their referenced issue id.
+    pub fn iter_linked_commits(&self) -> impl Iterator<Item = (&IssueId, &Vec<LinkedCommit>)> {
+        self.linked_commits.iter()
+    }
+
+    /// The HEAD oid the most recent git history scan reached, or `None` if it has never run.
+    pub fn last_scanned_oid(&self) -> Option<&str> {
+        self.last_scanned_oid.as_deref()
+    }
+
     /// Applies a single event to update the projected state.
     pub fn apply_event(&mut self, event: IssueEvent) {
         match event {
@@ -67,6 +199,63 @@ impl Issues {
                     .entry(issue_id)
                     .and_modify(|issue| issue.priority = priority);
             }
+            IssueEvent::CommitLinked { issue_id, commit } => {
+                self.linked_commits
+                    .entry(issue_id)
+                    .or_default()
+                    .push(commit);
+            }
+            IssueEvent::GitScanned { up_to_oid } => {
+                self.last_scanned_oid = Some(up_to_oid);
+            }
+            IssueEvent::IssueDeleted { issue_id } => {
+                self.issues.remove(&issue_id);
+            }
+        }
+    }
+
+    /// Computes the inverse of `event` against the state just before `event` is applied, plus a
+    /// short human description for the undo/redo status-line message (see `App::record_event`).
+    ///
+    /// Must be called before `event` is applied (e.g. before `append_to_log`), since the inverse
+    /// of `StatusChanged`/`PriorityChanged` needs the *prior* value, which only exists in `self`
+    /// up to that point.
+    ///
+    /// Returns `None` for event kinds that aren't user-undoable: `CommentAdded` (comments aren't
+    /// individually addressable, so there's nothing to remove), `CommitLinked`/`GitScanned`
+    /// (bookkeeping from a git scan, not a user action), and `IssueDeleted` itself (an undo's own
+    /// inverse is reconstructed from the original event at undo time, not by inverting twice; see
+    /// `App::undo`).
+    pub fn invert_event(&self, event: &IssueEvent) -> Option<(IssueEvent, String)> {
+        match event {
+            IssueEvent::IssueCreated(issue) => Some((
+                IssueEvent::IssueDeleted { issue_id: issue.id },
+                format!("create issue #{}", issue.id),
+            )),
+            IssueEvent::StatusChanged { issue_id, .. } => {
+                let prior = self.get_issue(issue_id)?.status;
+                Some((
+                    IssueEvent::StatusChanged {
+                        issue_id: *issue_id,
+                        status: prior,
+                    },
+                    format!("status change on #{issue_id}"),
+                ))
+            }
+            IssueEvent::PriorityChanged { issue_id, .. } => {
+                let prior = self.get_issue(issue_id)?.priority;
+                Some((
+                    IssueEvent::PriorityChanged {
+                        issue_id: *issue_id,
+                        priority: prior,
+                    },
+                    format!("priority change on #{issue_id}"),
+                ))
+            }
+            IssueEvent::CommentAdded(_)
+            | IssueEvent::CommitLinked { .. }
+            | IssueEvent::GitScanned { .. }
+            | IssueEvent::IssueDeleted { .. } => None,
         }
     }
 
@@ -84,17 +273,34 @@ impl Issues {
         issues
     }
 
-    /// Loads Issues state from a JSONL file where each line is an `IssueEvent`.
+    /// Loads Issues state from a JSONL file where each line is a tamper-evident chained record
+    /// (see [`Self::append_to_log`]) or, for a log predating the hash chain, a bare `IssueEvent`.
     ///
-    /// Reads a JSONL (JSON Lines) file where each line contains a serialized
-    /// `IssueEvent`. The events are applied in order to reconstruct the projected
-    /// state. Empty lines are ignored.
+    /// Prefers [`Self::load_snapshot_then_tail`], which replays only the events recorded after a
+    /// sidecar snapshot when one exists and is still consistent with the log, falling back to
+    /// replaying the whole file otherwise. This does not verify the hash chain; use
+    /// [`Self::verify_jsonl_file`] for that.
     ///
     /// # Errors
     ///
     /// Returns an error if the file cannot be read, if JSON deserialization fails
     /// for any line, or if the file format is invalid.
     pub fn from_jsonl_file<P>(path: P) -> Result<Self, Report<IssuesEventError>>
+    where
+        P: AsRef<Path>,
+    {
+        Self::load_snapshot_then_tail(path)
+    }
+
+    /// Replays every event in the log at `path` from scratch, ignoring any snapshot. Empty lines
+    /// are ignored. This is what [`Self::load_snapshot_then_tail`] falls back to when there's no
+    /// usable snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, if JSON deserialization fails
+    /// for any line, or if the file format is invalid.
+    fn replay_all<P>(path: P) -> Result<Self, Report<IssuesEventError>>
     where
         P: AsRef<Path>,
     {
@@ -102,29 +308,230 @@ impl Issues {
         let content = std::fs::read_to_string(path)
             .change_context(IssuesEventError)
             .attach_with(|| format!("failed to read file {:?}", path.display()))?;
+        Self::replay_content(&content)
+    }
 
-        let mut events = Vec::new();
+    /// Replays every event in already-read-in-memory log `content` from scratch. The shared
+    /// implementation behind [`Self::replay_all`] and [`Self::reload_incremental`]'s
+    /// non-append-rewrite fallback, both of which need this same from-scratch projection but
+    /// differ in whether they still need to read the file themselves.
+    fn replay_content(content: &str) -> Result<Self, Report<IssuesEventError>> {
+        let mut issues = Self::default();
         for (idx, line) in content.lines().enumerate() {
             let line = line.trim();
             if line.is_empty() {
                 continue;
             }
-            let event = serde_json::from_str::<IssueEvent>(line)
-                .change_context(IssuesEventError)
-                .attach_with(|| format!("failed to deserialize event at line {}", idx + 1))
-                .attach_with(|| format!("content: {line}"))?;
-            events.push(event);
+            match parse_log_line(line, idx + 1)? {
+                LogLine::Chained { hash, event, .. } => {
+                    issues.apply_event(event);
+                    issues.tip_hash = hash;
+                }
+                LogLine::Legacy(event) => issues.apply_event(event),
+            }
+        }
+
+        issues.last_read_offset = content.len() as u64;
+        issues.last_read_line_count = content.lines().count();
+        Ok(issues)
+    }
+
+    /// Loads Issues state for the log at `path` from its sidecar snapshot (see
+    /// [`Self::write_snapshot`]) when one exists and is still consistent with the log, replaying
+    /// only the events appended since; falls back to [`Self::replay_all`] otherwise.
+    ///
+    /// A snapshot is considered consistent if the log hasn't shrunk past the snapshot's recorded
+    /// offset and the chained record at that offset (if any) still hashes to what the snapshot
+    /// recorded as its tip — catching both truncation and a log that was replaced out from under
+    /// it. An unreadable, corrupt, or inconsistent snapshot is treated as absent rather than an
+    /// error, since [`Self::replay_all`] can always reconstruct the same state from the log alone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the log file itself cannot be read or fails to parse.
+    pub fn load_snapshot_then_tail<P>(path: P) -> Result<Self, Report<IssuesEventError>>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+
+        match Self::try_load_snapshot(path)? {
+            Some(mut issues) => {
+                issues.reload_incremental(path)?;
+                Ok(issues)
+            }
+            None => Self::replay_all(path),
+        }
+    }
+
+    /// Loads and validates the sidecar snapshot for `path`, returning `None` if it doesn't exist,
+    /// fails to parse, or is no longer consistent with the log (see
+    /// [`Self::load_snapshot_then_tail`]).
+    fn try_load_snapshot<P>(path: P) -> Result<Option<Self>, Report<IssuesEventError>>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let snapshot_path = snapshot_path(path);
+
+        let Ok(snapshot_bytes) = std::fs::read(&snapshot_path) else {
+            return Ok(None);
+        };
+        let Ok(snapshot) = serde_json::from_slice::<Snapshot>(&snapshot_bytes) else {
+            return Ok(None);
+        };
+
+        let content = std::fs::read_to_string(path)
+            .change_context(IssuesEventError)
+            .attach_with(|| format!("failed to read file {:?}", path.display()))?;
+
+        if (content.len() as u64) < snapshot.last_read_offset {
+            // The log is shorter than what the snapshot already read; it was truncated or
+            // replaced out from under us.
+            return Ok(None);
+        }
+
+        let Some(prefix) = content.get(..snapshot.last_read_offset as usize) else {
+            // `last_read_offset` doesn't land on a UTF-8 char boundary, which can't happen from
+            // a snapshot this code wrote itself -- treat it the same as any other inconsistent
+            // snapshot (log truncated/replaced, tip hash mismatch) and fall back to a full replay.
+            return Ok(None);
+        };
+        let last_line = prefix
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .next_back();
+        let tip_consistent = match last_line {
+            Some(line) => matches!(
+                parse_log_line(line.trim(), snapshot.last_read_line_count),
+                Ok(LogLine::Chained { hash, .. }) if hash == snapshot.tip_hash
+            ),
+            None => snapshot.tip_hash == GENESIS_HASH,
+        };
+        if !tip_consistent {
+            return Ok(None);
         }
 
-        Ok(Self::from_events(events))
+        let mut issues = snapshot.issues;
+        issues.last_read_offset = snapshot.last_read_offset;
+        issues.last_read_line_count = snapshot.last_read_line_count;
+        issues.tip_hash = snapshot.tip_hash;
+        Ok(Some(issues))
+    }
+
+    /// Writes a sidecar snapshot of the current projected state for the log at `path` (see
+    /// [`snapshot_path`]), so a future [`Self::load_snapshot_then_tail`] can skip straight to
+    /// tailing the events appended after it instead of replaying the whole log.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the snapshot cannot be serialized or written.
+    pub fn write_snapshot<P>(&self, path: P) -> Result<(), Report<IssuesEventError>>
+    where
+        P: AsRef<Path>,
+    {
+        let snapshot_path = snapshot_path(path.as_ref());
+        let snapshot = Snapshot {
+            issues: self.clone(),
+            last_read_offset: self.last_read_offset,
+            last_read_line_count: self.last_read_line_count,
+            tip_hash: self.tip_hash,
+        };
+        let bytes = serde_json::to_vec(&snapshot)
+            .change_context(IssuesEventError)
+            .attach("failed to serialize snapshot")?;
+        std::fs::write(&snapshot_path, bytes)
+            .change_context(IssuesEventError)
+            .attach_with(|| {
+                format!(
+                    "failed to write snapshot file {:?}",
+                    snapshot_path.display()
+                )
+            })?;
+        Ok(())
+    }
+
+    /// Reads and applies any events appended to the event log at `path` since the last time it
+    /// was read (by `from_jsonl_file`, `append_to_log`, or a previous call to this method),
+    /// returning how many new events were applied, or, after the full-replay fallback described
+    /// below, the number of lines in the log.
+    ///
+    /// Only complete lines (ones followed by a newline) past [`Self::last_read_offset`] are
+    /// consumed; a trailing partial line (a write still in progress by another process) is left
+    /// for the next call. Used by `feat::log_watcher` to pick up changes made by another
+    /// `intrack` process, a git pull, or a sync tool without restarting.
+    ///
+    /// A log that's shorter than `last_read_offset`, or whose prefix up to `last_read_offset` no
+    /// longer lands on a UTF-8 char boundary, wasn't simply appended to since the last read --
+    /// it was replaced out from under this reload (compaction, `doctor --fix`, a git checkout, an
+    /// editor saving via rename). There's nothing sensible to tail from in that case, so this
+    /// falls back to replaying the whole file from scratch (see [`Self::replay_content`]) and
+    /// replaces the current state wholesale, the same way [`Self::load_snapshot_then_tail`] falls
+    /// back to [`Self::replay_all`] when its snapshot turns out to be inconsistent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, or if a line fails to deserialize as an
+    /// `IssueEvent`.
+    pub fn reload_incremental<P>(&mut self, path: P) -> Result<usize, Report<IssuesEventError>>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .change_context(IssuesEventError)
+            .attach_with(|| format!("failed to read file {:?}", path.display()))?;
+
+        if (content.len() as u64) < self.last_read_offset {
+            *self = Self::replay_content(&content)?;
+            return Ok(self.last_read_line_count);
+        }
+
+        if (content.len() as u64) == self.last_read_offset {
+            return Ok(0);
+        }
+
+        let Some(new_content) = content.get(self.last_read_offset as usize..) else {
+            *self = Self::replay_content(&content)?;
+            return Ok(self.last_read_line_count);
+        };
+        let complete_len = new_content.rfind('\n').map_or(0, |idx| idx + 1);
+        let complete = &new_content[..complete_len];
+
+        let mut applied = 0;
+        for (idx, line) in complete.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let line_no = self.last_read_line_count + idx + 1;
+            match parse_log_line(line, line_no)? {
+                LogLine::Chained { hash, event, .. } => {
+                    self.apply_event(event);
+                    self.tip_hash = hash;
+                }
+                LogLine::Legacy(event) => self.apply_event(event),
+            }
+            applied += 1;
+        }
+
+        self.last_read_offset += complete_len as u64;
+        self.last_read_line_count += complete.lines().count();
+
+        Ok(applied)
     }
 
     /// Appends a new event to the event log file and applies it to the projected state.
     ///
-    /// Serializes the event to JSON and appends it as a single line to the event log.
-    /// After successfully writing to the file, the event is applied to update the
+    /// Serializes the event to JSON, chains it onto the tamper-evident hash chain (see
+    /// [`chain_hash`] / [`Self::verify_jsonl_file`]), and appends the resulting record as a single line to
+    /// the event log. After successfully writing to the file, the event is applied to update the
     /// current state in memory. The file is created if it doesn't exist.
     ///
+    /// Accepts anything convertible to an `IssueEvent` (an `Issue` or `Comment` directly, via
+    /// their `From` impls, as well as an `IssueEvent` itself), so callers creating a new issue
+    /// or comment don't need to wrap it first.
+    ///
     /// # Errors
     ///
     /// Returns an error if the file cannot be opened for appending, if the event
@@ -132,12 +539,13 @@ impl Issues {
     pub fn append_to_log<P>(
         &mut self,
         path: P,
-        event: &IssueEvent,
+        event: impl Into<IssueEvent>,
     ) -> Result<(), Report<IssuesEventError>>
     where
         P: AsRef<Path>,
     {
         let path = path.as_ref();
+        let event = event.into();
         let mut file = OpenOptions::new()
             .append(true)
             .create(true)
@@ -149,15 +557,202 @@ impl Issues {
             .change_context(IssuesEventError)
             .attach("failed to serialize event")?;
 
-        file.write_all(event_json.as_bytes())
+        let prev = self.tip_hash;
+        let hash = chain_hash(prev, &event_json);
+        let record = ChainedRecord {
+            hash: format!("{hash:016x}"),
+            prev: format!("{prev:016x}"),
+            event: RawValue::from_string(event_json)
+                .change_context(IssuesEventError)
+                .attach("failed to box serialized event as raw JSON")?,
+        };
+        let line = serde_json::to_string(&record)
+            .change_context(IssuesEventError)
+            .attach("failed to serialize chained log record")?;
+
+        file.write_all(line.as_bytes())
             .change_context(IssuesEventError)
             .attach("failed to write event JSONL to file")?;
         file.write_all(b"\n")
             .change_context(IssuesEventError)
             .attach("failed to write newline to file")?;
 
-        self.apply_event(event.clone());
+        self.apply_event(event);
+        self.tip_hash = hash;
+
+        // This write is already reflected in memory, so count it against `last_read_offset`/
+        // `last_read_line_count` too; otherwise the log-watcher's next `reload_incremental` would
+        // see it as "new" on disk and apply it a second time.
+        self.last_read_offset += line.len() as u64 + 1;
+        self.last_read_line_count += 1;
+
+        Ok(())
+    }
+
+    /// Re-reads the event log at `path` independently of any in-memory state and recomputes each
+    /// chained record's hash from the running `prev`, returning an error attached with the line
+    /// number of the first one that doesn't match what's recomputed — evidence of truncation,
+    /// reordering, or a manual edit.
+    ///
+    /// Lines in the legacy unchained bare-event format don't participate in the chain and are
+    /// skipped; a log made up entirely of them verifies successfully, since there's nothing to
+    /// check.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, a line fails to deserialize, or the hash
+    /// chain is broken, with the offending line number attached.
+    pub fn verify_jsonl_file<P>(path: P) -> Result<(), Report<IssuesEventError>>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .change_context(IssuesEventError)
+            .attach_with(|| format!("failed to read file {:?}", path.display()))?;
+
+        let mut tip = GENESIS_HASH;
+        for (idx, line) in content.lines().enumerate() {
+            let line_no = idx + 1;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let LogLine::Chained {
+                hash,
+                prev,
+                event_json,
+                ..
+            } = parse_log_line(line, line_no)?
+            else {
+                continue;
+            };
+
+            (prev == tip && chain_hash(prev, &event_json) == hash)
+                .then_some(())
+                .ok_or(IssuesEventError)
+                .attach_with(|| format!("hash chain verification failed at line {line_no}"))?;
+            tip = hash;
+        }
 
         Ok(())
     }
+
+    /// Rewrites `out_path` from scratch with `events`, applying each [`Diagnostic`]'s [`Fix`]
+    /// (as produced by [`lint_events`]) along the way: events marked [`Fix::DropEvent`] are
+    /// omitted, and an event paired with [`Fix::SynthesizeIssue`] gets that placeholder issue
+    /// inserted immediately before it. The result is written through [`Self::append_to_log`], so
+    /// `out_path` ends up as a freshly hash-chained log starting from [`GENESIS_HASH`].
+    ///
+    /// Returns the `Issues` projection for the cleaned log, so the caller can report what it now
+    /// contains without a separate `from_jsonl_file` call.
+    ///
+    /// `diagnostics` that don't carry a [`Fix`], or whose `line` doesn't land on an event in
+    /// `events`, are ignored; the event (if any) passes through unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `out_path` cannot be created or written to.
+    pub fn apply_fixes<P>(
+        events: &[IssueEvent],
+        diagnostics: &[Diagnostic],
+        out_path: P,
+    ) -> Result<Self, Report<IssuesEventError>>
+    where
+        P: AsRef<Path>,
+    {
+        let out_path = out_path.as_ref();
+        std::fs::File::create(out_path)
+            .change_context(IssuesEventError)
+            .attach_with(|| {
+                format!("failed to create cleaned log file {:?}", out_path.display())
+            })?;
+
+        let mut drop_lines = HashSet::new();
+        let mut synthesize_before: HashMap<usize, Issue> = HashMap::new();
+        for diagnostic in diagnostics {
+            match &diagnostic.fix {
+                Some(Fix::DropEvent) => {
+                    drop_lines.insert(diagnostic.line);
+                }
+                Some(Fix::SynthesizeIssue(issue)) => {
+                    synthesize_before.insert(diagnostic.line, issue.clone());
+                }
+                None => {}
+            }
+        }
+
+        let mut issues = Self::default();
+        for (idx, event) in events.iter().enumerate() {
+            let line = idx + 1;
+            if let Some(placeholder) = synthesize_before.remove(&line) {
+                issues.append_to_log(out_path, placeholder)?;
+            }
+            if drop_lines.contains(&line) {
+                continue;
+            }
+            issues.append_to_log(out_path, event.clone())?;
+        }
+
+        Ok(issues)
+    }
+
+    /// Reads and parses every event in the log at `path`, in order, without applying them to any
+    /// projection. Used by [`Self::compact_jsonl_file`] and by the `intrack doctor` subcommand
+    /// (see [`lint_events`]) that need the raw event sequence rather than the derived state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or a line fails to deserialize.
+    pub fn read_events<P>(path: P) -> Result<Vec<IssueEvent>, Report<IssuesEventError>>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .change_context(IssuesEventError)
+            .attach_with(|| format!("failed to read file {:?}", path.display()))?;
+
+        content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .enumerate()
+            .map(|(idx, line)| {
+                Ok(match parse_log_line(line, idx + 1)? {
+                    LogLine::Chained { event, .. } => event,
+                    LogLine::Legacy(event) => event,
+                })
+            })
+            .collect()
+    }
+
+    /// Reads the event log at `path`, folds it into its minimal equivalent stream via
+    /// [`compact_events`], and rewrites `path` with just that stream, freshly hash-chained from
+    /// [`GENESIS_HASH`]. The final projected state is unchanged; only the on-disk log size and
+    /// future replay time shrink.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the log cannot be read, or the rewritten file cannot be created or
+    /// written.
+    pub fn compact_jsonl_file<P>(path: P) -> Result<Self, Report<IssuesEventError>>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let events = Self::read_events(path)?;
+        let compacted = compact_events(&events);
+
+        std::fs::File::create(path)
+            .change_context(IssuesEventError)
+            .attach_with(|| format!("failed to truncate event log {:?}", path.display()))?;
+
+        let mut issues = Self::default();
+        for event in compacted {
+            issues.append_to_log(path, event)?;
+        }
+
+        Ok(issues)
+    }
 }
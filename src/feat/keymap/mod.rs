@@ -0,0 +1,217 @@
+mod parse;
+
+pub use parse::KeyDescriptorParseError;
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Deserializer};
+
+use crate::feat::tui::{KeyCode, KeyModifiers};
+
+/// A page/widget that owns its own set of keybindings.
+///
+/// Each `Context` maps to an independent [`Action`] table so the same physical
+/// key can mean different things depending on which part of the UI has focus.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Deserialize)]
+pub enum Context {
+    IssueTable,
+    IssueThread,
+    Board,
+}
+
+/// A named, user-bindable operation.
+///
+/// Input handlers resolve an incoming key event to one of these via the
+/// [`Keymap`] instead of matching `KeyCode`/`KeyModifiers` literals directly,
+/// which is what makes rebinding possible.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Deserialize)]
+pub enum Action {
+    Back,
+    CursorNext,
+    CursorPrev,
+    PageDown,
+    PageUp,
+    ToggleHelp,
+    AddComment,
+    Quit,
+    Suspend,
+    EditColumns,
+    CreateIssue,
+    SortDescending,
+    SortAscending,
+    SortNextColumn,
+    SortPreviousColumn,
+    ToggleStatus,
+    BumpPriority,
+    ToggleMark,
+    InspectCell,
+    SyncGit,
+    ColumnPageNext,
+    ColumnPagePrevious,
+    OpenThread,
+    FocusFilter,
+    Undo,
+    Redo,
+    ToggleBoard,
+}
+
+/// Maps a parsed key combination to an [`Action`] for every [`Context`].
+///
+/// Built from the built-in defaults (which reproduce today's hardcoded
+/// bindings) and optionally overlaid with user-supplied bindings from a
+/// config file.
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: HashMap<Context, HashMap<(KeyCode, KeyModifiers), Action>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let mut bindings = HashMap::new();
+        bindings.insert(Context::IssueThread, Self::default_issue_thread_bindings());
+        bindings.insert(Context::IssueTable, Self::default_issue_table_bindings());
+        bindings.insert(Context::Board, Self::default_board_bindings());
+        Self { bindings }
+    }
+}
+
+impl Keymap {
+    /// Reproduces today's hardcoded `IssueThreadPageInput::handle` bindings.
+    fn default_issue_thread_bindings() -> HashMap<(KeyCode, KeyModifiers), Action> {
+        let mut map = HashMap::new();
+        for (descriptor, action) in [
+            ("<q>", Action::Back),
+            ("<esc>", Action::Back),
+            ("<down>", Action::CursorNext),
+            ("<j>", Action::CursorNext),
+            ("<up>", Action::CursorPrev),
+            ("<k>", Action::CursorPrev),
+            ("<Ctrl-d>", Action::PageDown),
+            ("<Ctrl-u>", Action::PageUp),
+            ("<?>", Action::ToggleHelp),
+            ("<a>", Action::AddComment),
+        ] {
+            let (code, mods) = parse::parse_key_descriptor(descriptor)
+                .expect("built-in key descriptor must parse");
+            map.insert((code, mods), action);
+        }
+        map
+    }
+
+    /// Reproduces today's hardcoded top-level/`IssueTablePageInput` bindings, including the
+    /// column-paging, sorting, and bulk-action shortcuts `IssueTablePageInput::apply` dispatches
+    /// on.
+    fn default_issue_table_bindings() -> HashMap<(KeyCode, KeyModifiers), Action> {
+        let mut map = HashMap::new();
+        for (descriptor, action) in [
+            ("<down>", Action::CursorNext),
+            ("<j>", Action::CursorNext),
+            ("<up>", Action::CursorPrev),
+            ("<k>", Action::CursorPrev),
+            ("<?>", Action::ToggleHelp),
+            ("<q>", Action::Quit),
+            ("<Ctrl-z>", Action::Suspend),
+            ("<c>", Action::EditColumns),
+            ("<n>", Action::CreateIssue),
+            ("<Shift-J>", Action::SortDescending),
+            ("<Shift-j>", Action::SortDescending),
+            ("<Shift-down>", Action::SortDescending),
+            ("<Shift-K>", Action::SortAscending),
+            ("<Shift-k>", Action::SortAscending),
+            ("<Shift-up>", Action::SortAscending),
+            ("<Shift-L>", Action::SortNextColumn),
+            ("<Shift-l>", Action::SortNextColumn),
+            ("<Shift-right>", Action::SortNextColumn),
+            ("<Shift-H>", Action::SortPreviousColumn),
+            ("<Shift-h>", Action::SortPreviousColumn),
+            ("<Shift-left>", Action::SortPreviousColumn),
+            ("<s>", Action::ToggleStatus),
+            ("<p>", Action::BumpPriority),
+            ("<v>", Action::ToggleMark),
+            ("<space>", Action::ToggleMark),
+            ("<i>", Action::InspectCell),
+            ("<g>", Action::SyncGit),
+            ("<right>", Action::ColumnPageNext),
+            ("<l>", Action::ColumnPageNext),
+            ("<left>", Action::ColumnPagePrevious),
+            ("<h>", Action::ColumnPagePrevious),
+            ("<enter>", Action::OpenThread),
+            ("/", Action::FocusFilter),
+            ("<u>", Action::Undo),
+            ("<Ctrl-r>", Action::Redo),
+            ("<tab>", Action::ToggleBoard),
+        ] {
+            let (code, mods) = parse::parse_key_descriptor(descriptor)
+                .expect("built-in key descriptor must parse");
+            map.insert((code, mods), action);
+        }
+        map
+    }
+
+    /// Reproduces the kanban board's hardcoded `BoardPageInput::handle`/`apply_board` bindings.
+    fn default_board_bindings() -> HashMap<(KeyCode, KeyModifiers), Action> {
+        let mut map = HashMap::new();
+        for (descriptor, action) in [
+            ("<tab>", Action::ToggleBoard),
+            ("<down>", Action::CursorNext),
+            ("<j>", Action::CursorNext),
+            ("<up>", Action::CursorPrev),
+            ("<k>", Action::CursorPrev),
+            ("<left>", Action::ColumnPagePrevious),
+            ("<h>", Action::ColumnPagePrevious),
+            ("<right>", Action::ColumnPageNext),
+            ("<l>", Action::ColumnPageNext),
+            ("<enter>", Action::OpenThread),
+            ("<Alt-s>", Action::ToggleStatus),
+            ("<?>", Action::ToggleHelp),
+            ("<q>", Action::Quit),
+            ("<Ctrl-z>", Action::Suspend),
+        ] {
+            let (code, mods) = parse::parse_key_descriptor(descriptor)
+                .expect("built-in key descriptor must parse");
+            map.insert((code, mods), action);
+        }
+        map
+    }
+
+    /// Resolves a `KeyCode`/`KeyModifiers` pair to the bound [`Action`] for the given context.
+    pub fn resolve(&self, context: Context, code: KeyCode, mods: KeyModifiers) -> Option<Action> {
+        self.bindings.get(&context)?.get(&(code, mods)).copied()
+    }
+
+    /// Overlays user-supplied bindings (by descriptor string) onto the built-in defaults.
+    ///
+    /// Unknown descriptors are skipped; a malformed descriptor should not prevent the rest
+    /// of the user's config from loading.
+    fn apply_overrides(&mut self, raw: HashMap<String, HashMap<String, Action>>) {
+        for (context_name, binds) in raw {
+            let context = match context_name.as_str() {
+                "IssueTable" => Context::IssueTable,
+                "IssueThread" => Context::IssueThread,
+                "Board" => Context::Board,
+                _ => continue,
+            };
+            let table = self.bindings.entry(context).or_default();
+            for (descriptor, action) in binds {
+                if let Ok((code, mods)) = parse::parse_key_descriptor(&descriptor) {
+                    table.insert((code, mods), action);
+                }
+            }
+        }
+    }
+}
+
+/// Deserializes a `Keymap` from a `{ Context: { "<descriptor>": Action } }` table,
+/// overlaying it onto the built-in default bindings so an unspecified key keeps
+/// its current behavior.
+impl<'de> Deserialize<'de> for Keymap {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw: HashMap<String, HashMap<String, Action>> = HashMap::deserialize(deserializer)?;
+        let mut keymap = Keymap::default();
+        keymap.apply_overrides(raw);
+        Ok(keymap)
+    }
+}
@@ -0,0 +1,99 @@
+use wherror::Error;
+
+use crate::feat::tui::{KeyCode, KeyModifiers};
+
+/// Error returned when a `"<mod-key>"`-style key descriptor cannot be parsed.
+#[derive(Debug, Error)]
+#[error(debug)]
+pub struct KeyDescriptorParseError;
+
+/// Parses a key descriptor string into a `(KeyCode, KeyModifiers)` pair.
+///
+/// Accepts bare characters (`"q"`, `"?"`), named keys wrapped in angle brackets
+/// (`"<esc>"`, `"<up>"`, `"<down>"`), and modifier-prefixed combinations joined by `-`
+/// (`"<Ctrl-d>"`, `"<Alt-Shift-x>"`). Modifier and key names are matched
+/// case-insensitively.
+pub fn parse_key_descriptor(s: &str) -> Result<(KeyCode, KeyModifiers), KeyDescriptorParseError> {
+    let inner = s.strip_prefix('<').and_then(|s| s.strip_suffix('>'));
+    let Some(inner) = inner else {
+        // A bare, unbracketed single character, e.g. "q".
+        let mut chars = s.chars();
+        let ch = chars.next().ok_or(KeyDescriptorParseError)?;
+        if chars.next().is_some() {
+            return Err(KeyDescriptorParseError);
+        }
+        return Ok((KeyCode::Char(ch), KeyModifiers::NONE));
+    };
+
+    let mut parts: Vec<&str> = inner.split('-').collect();
+    let key_part = parts.pop().ok_or(KeyDescriptorParseError)?;
+
+    let mut mods = KeyModifiers::NONE;
+    for part in parts {
+        match part.to_lowercase().as_str() {
+            "ctrl" | "control" => mods |= KeyModifiers::CONTROL,
+            "alt" => mods |= KeyModifiers::ALT,
+            "shift" => mods |= KeyModifiers::SHIFT,
+            _ => return Err(KeyDescriptorParseError),
+        }
+    }
+
+    let code = match key_part.to_lowercase().as_str() {
+        "esc" | "escape" => KeyCode::Esc,
+        "enter" | "return" | "cr" => KeyCode::Enter,
+        "tab" => KeyCode::Tab,
+        "backspace" | "bs" => KeyCode::Backspace,
+        "space" => KeyCode::Char(' '),
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "del" | "delete" => KeyCode::Delete,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        _ => {
+            // Named keywords above are matched case-insensitively, but a bare character
+            // is not: terminals report Shift-modified letters with the case preserved
+            // (e.g. `(Char('J'), SHIFT)`), so "<Shift-J>" and "<Shift-j>" must stay distinct.
+            let mut chars = key_part.chars();
+            let ch = chars.next().ok_or(KeyDescriptorParseError)?;
+            if chars.next().is_some() {
+                return Err(KeyDescriptorParseError);
+            }
+            KeyCode::Char(ch)
+        }
+    };
+
+    Ok((code, mods))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("q", (KeyCode::Char('q'), KeyModifiers::NONE))]
+    #[case("<q>", (KeyCode::Char('q'), KeyModifiers::NONE))]
+    #[case("<esc>", (KeyCode::Esc, KeyModifiers::NONE))]
+    #[case("<Ctrl-d>", (KeyCode::Char('d'), KeyModifiers::CONTROL))]
+    #[case("<Ctrl-u>", (KeyCode::Char('u'), KeyModifiers::CONTROL))]
+    #[case("<Ctrl-z>", (KeyCode::Char('z'), KeyModifiers::CONTROL))]
+    #[case("<down>", (KeyCode::Down, KeyModifiers::NONE))]
+    #[case("<Alt-Shift-x>", (KeyCode::Char('x'), KeyModifiers::ALT | KeyModifiers::SHIFT))]
+    #[case("<Shift-J>", (KeyCode::Char('J'), KeyModifiers::SHIFT))]
+    #[case("<Shift-j>", (KeyCode::Char('j'), KeyModifiers::SHIFT))]
+    #[case("<ESC>", (KeyCode::Esc, KeyModifiers::NONE))]
+    fn parses_known_descriptors(#[case] input: &str, #[case] expected: (KeyCode, KeyModifiers)) {
+        assert_eq!(parse_key_descriptor(input).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("<>")]
+    #[case("<Bogus-d>")]
+    #[case("qq")]
+    fn rejects_invalid_descriptors(#[case] input: &str) {
+        assert!(parse_key_descriptor(input).is_err());
+    }
+}
@@ -0,0 +1,81 @@
+use std::path::{Path, PathBuf};
+
+use error_stack::{Report, ResultExt};
+use notify::{Event as NotifyEvent, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::sync::mpsc::UnboundedSender;
+use wherror::Error;
+
+use crate::feat::tui::Event;
+
+/// Error type for event-log watching operations.
+///
+/// This error is returned when the underlying filesystem watcher cannot be created or
+/// configured to watch the event log's directory.
+#[derive(Debug, Error)]
+#[error(debug)]
+pub struct LogWatcherError;
+
+/// Watches the event log for modifications (another `intrack` instance, a git pull, a sync tool)
+/// and forwards an `Event::LogChanged` for each one. Changes are not filtered by origin: this
+/// process's own appends are forwarded too, and the reload that follows finds no new lines.
+///
+/// `App::handle_event` reacts to that event by calling `Issues::reload_incremental`, so the
+/// running TUI picks up the change live instead of needing a restart.
+///
+/// Holds the underlying `notify` watcher alive for as long as this value lives; dropping it
+/// stops watching.
+#[derive(Debug)]
+pub struct LogWatcher {
+    _watcher: RecommendedWatcher,
+}
+
+/// Starts watching `path`'s event log for modifications, forwarding `Event::LogChanged`
+/// through `event_tx` whenever one is observed.
+///
+/// Watches the containing directory rather than `path` itself, so the watch survives tools that
+/// replace the file via rename instead of writing to it in place.
+///
+/// # Errors
+///
+/// Returns an error if the filesystem watcher cannot be created or cannot be registered for the
+/// event log's parent directory.
+pub fn watch<P>(
+    path: P,
+    event_tx: UnboundedSender<Event>,
+) -> Result<LogWatcher, Report<LogWatcherError>>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref().to_path_buf();
+    let parent = path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| PathBuf::from("."));
+    // Only `parent` is watched (non-recursively), so the file name identifies the log; the paths
+    // `notify` reports are joined onto the watched directory and needn't equal `path` as given.
+    let file_name = path.file_name().map(ToOwned::to_owned);
+
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<NotifyEvent>| {
+        let Ok(event) = res else {
+            return;
+        };
+        if !matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_)) {
+            return;
+        }
+        if event.paths.iter().any(|p| p.file_name() == file_name.as_deref()) {
+            // The receiver may already be gone (e.g. the `Tui` was dropped as the app quits);
+            // nothing useful to do about that here.
+            let _ = event_tx.send(Event::LogChanged);
+        }
+    })
+    .change_context(LogWatcherError)
+    .attach("failed to create event-log watcher")?;
+
+    watcher
+        .watch(&parent, RecursiveMode::NonRecursive)
+        .change_context(LogWatcherError)
+        .attach_with(|| format!("failed to watch {:?}", parent.display()))?;
+
+    Ok(LogWatcher { _watcher: watcher })
+}
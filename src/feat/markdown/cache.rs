@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use ratatui::text::Text;
+
+use super::render;
+
+/// Memoizes rendered Markdown so redraws don't re-parse and re-highlight unchanged comments.
+///
+/// Entries are keyed by a hash of the source content rather than a stable comment id (comments
+/// have none), which has the side benefit of invalidating itself for free: editing a comment's
+/// content changes its key, so the stale rendering is simply never looked up again. Old entries
+/// are never evicted, but the cache only lives as long as the issue thread page's state and is
+/// bounded by the number of comments a user actually views.
+#[derive(Debug, Default)]
+pub struct MarkdownCache {
+    entries: HashMap<u64, Text<'static>>,
+}
+
+impl MarkdownCache {
+    /// Returns the rendered `Text` for `content`, rendering and caching it on first access.
+    pub fn get_or_render(&mut self, content: &str) -> Text<'static> {
+        let mut hasher = DefaultHasher::new();
+        content.hash(&mut hasher);
+        let key = hasher.finish();
+
+        self.entries
+            .entry(key)
+            .or_insert_with(|| render(content))
+            .clone()
+    }
+}
@@ -0,0 +1,7 @@
+mod cache;
+mod render;
+mod wrap;
+
+pub use cache::MarkdownCache;
+pub use render::render;
+pub use wrap::wrap_text;
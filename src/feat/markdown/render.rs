@@ -0,0 +1,276 @@
+use std::sync::LazyLock;
+
+use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag, TagEnd};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span, Text};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Color as SynColor, ThemeSet};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+
+
+static SYNTAX_SET: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_newlines);
+static THEME_SET: LazyLock<ThemeSet> = LazyLock::new(ThemeSet::load_defaults);
+
+/// Indent prefixed to a list item's marker once for each level of nesting below the outermost
+/// list.
+const LIST_INDENT: &str = "  ";
+
+/// One level of list currently open, tracking what should be printed before the next item.
+enum ListLevel {
+    Bullet,
+    Ordered(u64),
+}
+
+/// Incremental state for turning a stream of [`pulldown_cmark::Event`]s into ratatui [`Line`]s.
+struct Renderer {
+    lines: Vec<Line<'static>>,
+    current: Vec<Span<'static>>,
+    lists: Vec<ListLevel>,
+    blockquote_depth: usize,
+    bold: bool,
+    italic: bool,
+    code_span: bool,
+    heading: bool,
+    in_code_block: bool,
+    code_block_lang: Option<String>,
+    code_block_buf: String,
+    /// Set at the start of a list item so the next text emitted gets the marker/indent prefix.
+    pending_item_marker: bool,
+}
+
+impl Renderer {
+    fn new() -> Self {
+        Self {
+            lines: Vec::new(),
+            current: Vec::new(),
+            lists: Vec::new(),
+            blockquote_depth: 0,
+            bold: false,
+            italic: false,
+            code_span: false,
+            heading: false,
+            in_code_block: false,
+            code_block_lang: None,
+            code_block_buf: String::new(),
+            pending_item_marker: false,
+        }
+    }
+
+    fn quote_prefix(&self) -> String {
+        "\u{2502} ".repeat(self.blockquote_depth)
+    }
+
+    fn text_style(&self) -> Style {
+        let mut style = Style::default();
+        if self.heading {
+            style = style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+        }
+        if self.bold {
+            style = style.add_modifier(Modifier::BOLD);
+        }
+        if self.italic {
+            style = style.add_modifier(Modifier::ITALIC);
+        }
+        if self.code_span {
+            style = style.add_modifier(Modifier::REVERSED);
+        }
+        if self.blockquote_depth > 0 {
+            style = style.fg(Color::DarkGray).add_modifier(Modifier::ITALIC);
+        }
+        style
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if self.current.is_empty() {
+            let prefix = self.quote_prefix();
+            if !prefix.is_empty() {
+                self.current
+                    .push(Span::styled(prefix, Style::default().fg(Color::DarkGray)));
+            }
+        }
+        if self.pending_item_marker {
+            self.pending_item_marker = false;
+            self.current.push(Span::raw(self.item_marker()));
+        }
+        self.current
+            .push(Span::styled(text.to_string(), self.text_style()));
+    }
+
+    fn item_marker(&mut self) -> String {
+        let depth = self.lists.len().saturating_sub(1);
+        let indent = LIST_INDENT.repeat(depth);
+        match self.lists.last_mut() {
+            Some(ListLevel::Ordered(n)) => {
+                let marker = format!("{indent}{n}. ");
+                *n += 1;
+                marker
+            }
+            Some(ListLevel::Bullet) | None => format!("{indent}\u{2022} "),
+        }
+    }
+
+    fn flush_line(&mut self) {
+        let spans = std::mem::take(&mut self.current);
+        self.lines.push(Line::from(spans));
+    }
+
+    fn blank_line(&mut self) {
+        if !matches!(self.lines.last(), Some(line) if line.spans.is_empty()) {
+            self.lines.push(Line::default());
+        }
+    }
+
+    fn start_code_block(&mut self, kind: CodeBlockKind<'_>) {
+        self.in_code_block = true;
+        self.code_block_buf.clear();
+        self.code_block_lang = match kind {
+            CodeBlockKind::Fenced(lang) if !lang.is_empty() => Some(lang.to_string()),
+            _ => None,
+        };
+    }
+
+    fn end_code_block(&mut self) {
+        self.in_code_block = false;
+        let lang = self.code_block_lang.take().unwrap_or_default();
+        for line in highlight_code_block(&lang, &self.code_block_buf) {
+            self.lines.push(line);
+        }
+        self.code_block_buf.clear();
+        self.blank_line();
+    }
+
+    fn handle(&mut self, event: Event<'_>) {
+        match event {
+            Event::Start(Tag::Heading { .. }) => {
+                self.heading = true;
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                self.heading = false;
+                self.flush_line();
+                self.blank_line();
+            }
+            Event::Start(Tag::Paragraph) => {}
+            Event::End(TagEnd::Paragraph) => {
+                self.flush_line();
+                if self.lists.is_empty() {
+                    self.blank_line();
+                }
+            }
+            Event::Start(Tag::List(start)) => {
+                self.lists.push(match start {
+                    Some(n) => ListLevel::Ordered(n),
+                    None => ListLevel::Bullet,
+                });
+            }
+            Event::End(TagEnd::List(_)) => {
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.blank_line();
+                }
+            }
+            Event::Start(Tag::Item) => {
+                self.pending_item_marker = true;
+            }
+            Event::End(TagEnd::Item) => {
+                self.flush_line();
+            }
+            Event::Start(Tag::BlockQuote(_)) => {
+                self.blockquote_depth += 1;
+            }
+            Event::End(TagEnd::BlockQuote(_)) => {
+                self.blockquote_depth = self.blockquote_depth.saturating_sub(1);
+                if self.blockquote_depth == 0 {
+                    self.blank_line();
+                }
+            }
+            Event::Start(Tag::CodeBlock(kind)) => self.start_code_block(kind),
+            Event::End(TagEnd::CodeBlock) => self.end_code_block(),
+            Event::Start(Tag::Emphasis) => self.italic = true,
+            Event::End(TagEnd::Emphasis) => self.italic = false,
+            Event::Start(Tag::Strong) => self.bold = true,
+            Event::End(TagEnd::Strong) => self.bold = false,
+            Event::Code(code) => {
+                self.code_span = true;
+                self.push_text(&code);
+                self.code_span = false;
+            }
+            Event::Text(text) => {
+                if self.in_code_block {
+                    self.code_block_buf.push_str(&text);
+                } else {
+                    self.push_text(&text);
+                }
+            }
+            Event::SoftBreak => self.push_text(" "),
+            Event::HardBreak => self.flush_line(),
+            Event::Rule => {
+                self.blank_line();
+                self.lines.push(Line::from(Span::styled(
+                    "\u{2500}".repeat(40),
+                    Style::default().fg(Color::DarkGray),
+                )));
+                self.blank_line();
+            }
+            _ => {}
+        }
+    }
+
+    fn finish(mut self) -> Text<'static> {
+        if !self.current.is_empty() {
+            self.flush_line();
+        }
+        while matches!(self.lines.last(), Some(line) if line.spans.is_empty()) {
+            self.lines.pop();
+        }
+        Text::from(self.lines)
+    }
+}
+
+/// Converts a syntect highlighting color into the ratatui RGB equivalent.
+fn syn_color_to_ratatui(color: SynColor) -> Color {
+    Color::Rgb(color.r, color.g, color.b)
+}
+
+/// Runs `code` through syntect using the syntax registered for `language`, producing one
+/// ratatui `Line` per source line with RGB-styled spans. Falls back to plain text styling
+/// when `language` has no matching syntax definition.
+fn highlight_code_block(language: &str, code: &str) -> Vec<Line<'static>> {
+    let syntax = SYNTAX_SET
+        .find_syntax_by_token(language)
+        .unwrap_or_else(|| SYNTAX_SET.find_syntax_plain_text());
+    let theme = &THEME_SET.themes["base16-ocean.dark"];
+    let mut highlighter = HighlightLines::new(syntax, theme);
+
+    LinesWithEndings::from(code)
+        .map(|line| {
+            let ranges = highlighter
+                .highlight_line(line, &SYNTAX_SET)
+                .unwrap_or_default();
+            let spans: Vec<Span<'static>> = ranges
+                .into_iter()
+                .map(|(style, text)| {
+                    Span::styled(
+                        text.trim_end_matches('\n').to_string(),
+                        Style::default().fg(syn_color_to_ratatui(style.foreground)),
+                    )
+                })
+                .collect();
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// Parses `content` as Markdown and renders it into a ratatui `Text` with styled headings,
+/// lists, inline code, blockquotes, and syntax-highlighted fenced code blocks.
+///
+/// The returned text is not wrapped to any particular width; use [`super::wrap_text`] at draw
+/// time once the available width is known.
+pub fn render(content: &str) -> Text<'static> {
+    let mut renderer = Renderer::new();
+    for event in Parser::new(content) {
+        renderer.handle(event);
+    }
+    renderer.finish()
+}
+
@@ -0,0 +1,41 @@
+use ratatui::text::{Line, Span, Text};
+
+/// Wraps a single rendered line to `width` columns, splitting on whitespace and preserving each
+/// word's style. Width-dependent, so this is recomputed every draw rather than cached alongside
+/// the parsed/highlighted `Text`.
+fn wrap_line(line: &Line<'static>, width: usize) -> Vec<Line<'static>> {
+    if width == 0 {
+        return vec![line.clone()];
+    }
+
+    let mut wrapped: Vec<Vec<Span<'static>>> = vec![Vec::new()];
+    let mut current_width = 0usize;
+
+    for span in &line.spans {
+        for word in span.content.split_inclusive(' ') {
+            if word.is_empty() {
+                continue;
+            }
+            let word_width = word.chars().count();
+            if current_width > 0 && current_width + word_width > width {
+                wrapped.push(Vec::new());
+                current_width = 0;
+            }
+            current_width += word_width;
+            wrapped
+                .last_mut()
+                .expect("wrapped always has at least one line")
+                .push(Span::styled(word.to_string(), span.style));
+        }
+    }
+
+    wrapped.into_iter().map(Line::from).collect()
+}
+
+/// Rewraps every line of a previously-rendered Markdown `Text` to fit `width` columns.
+///
+/// This is a cheap, style-preserving word wrap applied at draw time, separate from the
+/// (cached) parse+highlight step, since the available width changes with terminal size.
+pub fn wrap_text(text: &Text<'static>, width: usize) -> Vec<Line<'static>> {
+    text.lines.iter().flat_map(|line| wrap_line(line, width)).collect()
+}
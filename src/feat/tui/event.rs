@@ -3,6 +3,9 @@ pub type KeyEvent = crossterm::event::KeyEvent;
 pub type KeyModifiers = crossterm::event::KeyModifiers;
 pub type KeyCode = crossterm::event::KeyCode;
 pub type KeyEventKind = crossterm::event::KeyEventKind;
+pub type MouseEvent = crossterm::event::MouseEvent;
+pub type MouseEventKind = crossterm::event::MouseEventKind;
+pub type MouseButton = crossterm::event::MouseButton;
 
 /// Helper methods on [`Event`].
 pub trait EventExt {
@@ -14,6 +17,8 @@ pub trait EventExt {
     fn keypress(&self) -> Option<KeyCode>;
     /// Returns the modifiers held during a keypress.
     fn modifiers(&self) -> Option<KeyModifiers>;
+    /// Returns `Some` if the event is a mouse event.
+    fn mouse(&self) -> Option<MouseEvent>;
 }
 
 impl EventExt for Event {
@@ -48,4 +53,12 @@ impl EventExt for Event {
             None
         }
     }
+
+    fn mouse(&self) -> Option<MouseEvent> {
+        if let Event::Mouse(mouse) = self {
+            Some(*mouse)
+        } else {
+            None
+        }
+    }
 }
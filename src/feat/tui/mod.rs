@@ -2,14 +2,18 @@ mod event;
 mod state;
 mod wrapper;
 
-pub use event::{Event, EventExt, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+pub use event::{
+    Event, EventExt, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent,
+    MouseEventKind,
+};
 pub use state::TuiState;
-pub use wrapper::{Tui, TuiError};
+pub use wrapper::{KeyKindFilter, Tui, TuiError};
 
 #[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
 pub enum Page {
     #[default]
     IssueTable,
+    Board,
 }
 
 #[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
@@ -17,6 +21,7 @@ pub enum Focus {
     #[default]
     IssueTable,
     IssueTableFilter,
+    Board,
 }
 
 /// Return type from custom widgets during their input handling phase.
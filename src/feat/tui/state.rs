@@ -1,5 +1,6 @@
 use crate::feat::{
     tui::{Focus, Page},
+    tui_board::BoardState,
     tui_issue_table::IssueTableState,
 };
 
@@ -10,6 +11,12 @@ pub struct TuiState {
     focus: Focus,
 
     pub issue_table: IssueTableState,
+    pub board: BoardState,
+
+    /// A one-shot message to surface on the next render, e.g. a hook command that failed to
+    /// start, or an "undid: ..."/"redid: ..." confirmation. See `App::record_event`/`App::undo`/
+    /// `App::redo`.
+    status_message: Option<String>,
 }
 
 impl TuiState {
@@ -32,4 +39,14 @@ impl TuiState {
     pub fn set_page(&mut self, page: Page) {
         self.page = page;
     }
+
+    /// Records a message to show on the next render.
+    pub fn set_status_message(&mut self, message: String) {
+        self.status_message = Some(message);
+    }
+
+    /// Takes the pending message, if any, leaving `None` in its place so it's only shown once.
+    pub fn take_status_message(&mut self) -> Option<String> {
+        self.status_message.take()
+    }
 }
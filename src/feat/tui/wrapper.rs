@@ -1,5 +1,10 @@
 use std::{
+    io::Write,
     ops::{Deref, DerefMut},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
     time::Duration,
 };
 
@@ -7,7 +12,8 @@ use crossterm::{
     cursor,
     event::{
         DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
-        Event as CrosstermEvent, KeyEvent, KeyEventKind, MouseEvent,
+        Event as CrosstermEvent, KeyEvent, KeyEventKind, KeyboardEnhancementFlags, MouseEvent,
+        PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
     },
     terminal::{EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -37,6 +43,9 @@ pub enum Event {
     Error,
     /// Closed event, sent when the TUI is closed
     Closed,
+    /// Suspend event, asking the app to suspend to the shell so the backend can be torn down
+    /// before `SIGTSTP` is raised. The event loop never sends it; `Ctrl+z` arrives as `Key`
+    Suspend,
     /// Tick event, sent periodically at the configured tick rate
     Tick,
     /// Render event, sent periodically at the configured frame rate
@@ -57,6 +66,26 @@ pub enum Event {
     /// Resize event, sent when the terminal is resized
     /// Contains the new width and height
     Resize(u16, u16),
+    /// Sent when `feat::log_watcher` notices the event log was modified (by another `intrack`
+    /// instance, a git pull, a sync tool, or this process's own appends), so `App::handle_event`
+    /// can reload any new lines via `Issues::reload_incremental` and redraw.
+    LogChanged,
+}
+
+/// Which [`KeyEventKind`]s the event loop forwards as [`Event::Key`]; see [`Tui::with_key_kinds`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KeyKindFilter {
+    /// Forward only `Press`, collapsing `Repeat`/`Release` as crossterm does on terminals
+    /// without the keyboard enhancement protocol. The default, and the only option that makes
+    /// sense on a terminal `Tui::enter_raw_mode` didn't detect enhancement support for.
+    #[default]
+    PressOnly,
+    /// Forward `Press` and `Repeat`, still dropping `Release`. Enough for key-repeat-driven
+    /// navigation without consumers having to handle release bookkeeping.
+    PressRepeat,
+    /// Forward every kind, including `Release`. Needed for key-held-down behavior (games,
+    /// chorded shortcuts) on terminals that report it.
+    All,
 }
 
 /// Terminal User Interface wrapper for handling terminal events and rendering.
@@ -65,10 +94,15 @@ pub enum Event {
 /// It provides a high-level interface for building TUI applications using ratatui
 /// and crossterm. The TUI runs in raw mode with an alternate screen buffer
 /// and can handle keyboard, mouse, and terminal resize events.
+///
+/// Generic over the output writer `W` so the backend can target something other than stderr —
+/// stdout (when stderr is reserved for logs), a pipe, or an in-memory buffer for headless
+/// rendering tests. Defaults to `std::io::Stderr`, matching every existing caller; use
+/// [`Tui::with_writer`] to target something else.
 #[derive(Debug)]
-pub struct Tui {
+pub struct Tui<W: Write = std::io::Stderr> {
     /// The underlying ratatui terminal instance
-    pub terminal: ratatui::Terminal<Backend<std::io::Stderr>>,
+    pub terminal: ratatui::Terminal<Backend<W>>,
     /// Background task handle for the event loop
     pub task: JoinHandle<Result<(), Report<TuiError>>>,
     /// Cancellation token for stopping the event loop
@@ -85,6 +119,19 @@ pub struct Tui {
     pub mouse: bool,
     /// Whether bracketed paste mode is enabled
     pub paste: bool,
+    /// Whether the host terminal supports the Kitty keyboard enhancement protocol. `None` until
+    /// the first `enter_raw_mode` probes it; cached afterward so `resume()` doesn't reprobe on
+    /// every suspend/resume cycle.
+    pub kbd_enhancement: Option<bool>,
+    /// Which key event kinds are forwarded as `Event::Key`; see `with_key_kinds`.
+    pub key_kinds: KeyKindFilter,
+    /// Whether the render-interval timer only forwards `Event::Render` when something is
+    /// actually dirty; see `with_render_on_demand`.
+    pub render_on_demand: bool,
+    /// Set by `request_render()` and by the event loop on a resize/focus change; cleared by the
+    /// render-interval branch each time it forwards a frame. Shared with the background task so
+    /// both sides can flip it without going through the event channel.
+    needs_render: Arc<AtomicBool>,
 }
 
 /// Error type for TUI operations.
@@ -95,8 +142,8 @@ pub struct Tui {
 #[error(debug)]
 pub struct TuiError;
 
-impl Tui {
-    /// Creates a new TUI instance with default settings.
+impl Tui<std::io::Stderr> {
+    /// Creates a new TUI instance targeting stderr with default settings.
     ///
     /// Initializes a terminal with default tick rate of 4.0 Hz, frame rate of 60.0 Hz,
     /// and mouse capture and bracketed paste disabled.
@@ -105,10 +152,23 @@ impl Tui {
     ///
     /// Returns an error if the terminal cannot be initialized.
     pub fn new() -> Result<Self, Report<TuiError>> {
+        Self::with_writer(std::io::stderr())
+    }
+}
+
+impl<W: Write + 'static> Tui<W> {
+    /// Creates a new TUI instance writing to `writer` with default settings.
+    ///
+    /// Initializes a terminal with default tick rate of 4.0 Hz, frame rate of 60.0 Hz,
+    /// and mouse capture and bracketed paste disabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the terminal cannot be initialized.
+    pub fn with_writer(writer: W) -> Result<Self, Report<TuiError>> {
         let tick_rate = 4.0;
         let frame_rate = 60.0;
-        let terminal =
-            ratatui::Terminal::new(Backend::new(std::io::stderr())).change_context(TuiError)?;
+        let terminal = ratatui::Terminal::new(Backend::new(writer)).change_context(TuiError)?;
         let (event_tx, event_rx) = mpsc::unbounded_channel();
         let cancellation_token = CancellationToken::new();
         let task = tokio::spawn(async { Ok(()) });
@@ -124,6 +184,10 @@ impl Tui {
             tick_rate,
             mouse,
             paste,
+            kbd_enhancement: None,
+            key_kinds: KeyKindFilter::default(),
+            render_on_demand: false,
+            needs_render: Arc::new(AtomicBool::new(false)),
         })
     }
 
@@ -168,12 +232,49 @@ impl Tui {
         self
     }
 
+    /// Sets which key event kinds the event loop forwards as `Event::Key`.
+    ///
+    /// Defaults to `KeyKindFilter::PressOnly`, matching crossterm's own behavior on terminals
+    /// without the keyboard enhancement protocol. Pair with a terminal that `enter_raw_mode`
+    /// detected enhancement support for (see `kbd_enhancement`) to actually observe `Repeat`/
+    /// `Release` events; legacy terminals never emit them regardless of this setting.
+    #[must_use]
+    pub fn with_key_kinds(mut self, key_kinds: KeyKindFilter) -> Self {
+        self.key_kinds = key_kinds;
+        self
+    }
+
+    /// Enables or disables dirty-flag driven rendering.
+    ///
+    /// Off by default: the render-interval timer forwards `Event::Render` on every tick
+    /// regardless of whether anything changed, exactly as before. When enabled, that timer only
+    /// forwards a frame when `request_render()` was called or a resize/focus change occurred
+    /// since the last one, collapsing idle frames to near-zero CPU while still bounding redraw
+    /// latency by `frame_rate`.
+    #[must_use]
+    pub fn with_render_on_demand(mut self, render_on_demand: bool) -> Self {
+        self.render_on_demand = render_on_demand;
+        self
+    }
+
+    /// Marks the screen dirty so the next render-interval tick forwards an `Event::Render`, even
+    /// in `with_render_on_demand(true)` mode. No-op (but harmless) when that mode is off, since
+    /// every tick already renders.
+    pub fn request_render(&self) {
+        self.needs_render.store(true, Ordering::Relaxed);
+    }
+
     /// Starts the TUI event loop and background tasks.
     ///
     /// Spawns a background task that listens for terminal events (keyboard, mouse,
     /// resize, focus, paste) and periodically generates tick and render events.
     /// Sends an `Event::Init` event to signal initialization.
     ///
+    /// When `render_on_demand` is set, the render-interval tick only actually forwards
+    /// `Event::Render` if `request_render()` was called or a resize/focus event fired since the
+    /// last one; otherwise it's skipped, though the timer keeps running so redraw latency stays
+    /// bounded by `frame_rate`.
+    ///
     /// The event loop runs asynchronously and can be stopped by calling `stop()`
     /// or `cancel()`. Events can be received using the `next()` method.
     ///
@@ -188,6 +289,9 @@ impl Tui {
         self.cancellation_token = CancellationToken::new();
         let cancellation_token = self.cancellation_token.clone();
         let event_tx = self.event_tx.clone();
+        let key_kinds = self.key_kinds;
+        let render_on_demand = self.render_on_demand;
+        let needs_render = self.needs_render.clone();
         self.task = tokio::spawn(async move {
             let mut reader = crossterm::event::EventStream::new();
             let mut tick_interval = tokio::time::interval(tick_delay);
@@ -209,7 +313,12 @@ impl Tui {
                       Some(Ok(evt)) => {
                             match evt {
                             CrosstermEvent::Key(key) => {
-                                if key.kind == KeyEventKind::Press {
+                                let forward = match key_kinds {
+                                    KeyKindFilter::PressOnly => key.kind == KeyEventKind::Press,
+                                    KeyKindFilter::PressRepeat => matches!(key.kind, KeyEventKind::Press | KeyEventKind::Repeat),
+                                    KeyKindFilter::All => true,
+                                };
+                                if forward {
                                 event_tx.send(Event::Key(key)).change_context(TuiError).attach("failed to forward Key event")?;
                                 }
                             },
@@ -217,12 +326,15 @@ impl Tui {
                                 event_tx.send(Event::Mouse(mouse)).change_context(TuiError).attach("failed to forward Mouse event")?;
                             },
                             CrosstermEvent::Resize(x, y) => {
+                                needs_render.store(true, Ordering::Relaxed);
                                 event_tx.send(Event::Resize(x, y)).change_context(TuiError).attach("failed to forward Resize event")?;
                             },
                             CrosstermEvent::FocusLost => {
+                                needs_render.store(true, Ordering::Relaxed);
                                 event_tx.send(Event::FocusLost).change_context(TuiError).attach("failed to forward FocusLost event")?;
                             },
                             CrosstermEvent::FocusGained => {
+                                needs_render.store(true, Ordering::Relaxed);
                                 event_tx.send(Event::FocusGained).change_context(TuiError).attach("failed to forward FocusGained event")?;
                             },
                             CrosstermEvent::Paste(s) => {
@@ -240,7 +352,10 @@ impl Tui {
                       event_tx.send(Event::Tick).change_context(TuiError)?;
                   },
                   _ = render_delay => {
-                      event_tx.send(Event::Render).change_context(TuiError)?;
+                      let should_render = !render_on_demand || needs_render.swap(false, Ordering::Relaxed);
+                      if should_render {
+                          event_tx.send(Event::Render).change_context(TuiError)?;
+                      }
                   },
                 }
             }
@@ -275,33 +390,68 @@ impl Tui {
         Ok(())
     }
 
+    /// Returns the output writer backing the terminal, so `enter_raw_mode`/`exit_raw_mode` can
+    /// send terminal commands through the same stream ratatui draws to instead of assuming
+    /// stderr.
+    fn writer_mut(&mut self) -> &mut W {
+        self.terminal.backend_mut().writer_mut()
+    }
+
     /// Enters raw mode and alternate screen buffer.
     ///
     /// Enables raw mode for the terminal, enters the alternate screen buffer,
     /// and hides the cursor. Optionally enables mouse capture and bracketed paste
     /// mode based on the configuration set by `enable_mouse()` and `enable_paste()`.
     ///
+    /// Also probes whether the host terminal supports the Kitty keyboard enhancement protocol
+    /// (caching the result in `kbd_enhancement` so later calls, e.g. from `resume()`, don't
+    /// reprobe) and pushes its flags when supported, enabling key-release and key-repeat
+    /// events on terminals that can report them.
+    ///
+    /// Tells `init::panic_hook` whether `W` is `std::io::Stdout` so a panic mid-session restores
+    /// the stream this backend is actually writing through (see `Tui::with_writer`) instead of
+    /// always assuming stderr.
+    ///
     /// # Errors
     ///
     /// Returns an error if raw mode cannot be enabled or if terminal commands fail.
     pub fn enter_raw_mode(&mut self) -> Result<(), Report<TuiError>> {
+        crate::init::panic_hook::init();
         crossterm::terminal::enable_raw_mode().change_context(TuiError)?;
-        crossterm::execute!(std::io::stderr(), EnterAlternateScreen, cursor::Hide)
+        crossterm::execute!(self.writer_mut(), EnterAlternateScreen, cursor::Hide)
             .change_context(TuiError)?;
         if self.mouse {
-            crossterm::execute!(std::io::stderr(), EnableMouseCapture).change_context(TuiError)?;
+            crossterm::execute!(self.writer_mut(), EnableMouseCapture).change_context(TuiError)?;
         }
         if self.paste {
-            crossterm::execute!(std::io::stderr(), EnableBracketedPaste)
+            crossterm::execute!(self.writer_mut(), EnableBracketedPaste)
                 .change_context(TuiError)?;
         }
+        let kbd_enhancement = *self.kbd_enhancement.get_or_insert_with(|| {
+            crossterm::terminal::supports_keyboard_enhancement().unwrap_or(false)
+        });
+        if kbd_enhancement {
+            crossterm::execute!(
+                self.writer_mut(),
+                PushKeyboardEnhancementFlags(
+                    KeyboardEnhancementFlags::REPORT_EVENT_TYPES
+                        | KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
+                )
+            )
+            .change_context(TuiError)?;
+        }
+        crate::init::panic_hook::set_backend_flags(self.mouse, self.paste);
+        crate::init::panic_hook::set_active_stream_is_stdout(
+            std::any::TypeId::of::<W>() == std::any::TypeId::of::<std::io::Stdout>(),
+        );
         Ok(())
     }
 
     /// Exits raw mode and returns to the normal screen buffer.
     ///
     /// Exits raw mode, returns to the normal screen buffer, shows the cursor,
-    /// and disables mouse capture and bracketed paste mode if they were enabled.
+    /// and disables mouse capture and bracketed paste mode if they were enabled. Pops the
+    /// keyboard enhancement flags first if `enter_raw_mode` pushed them.
     /// Only performs cleanup if raw mode is currently enabled.
     ///
     /// # Errors
@@ -310,17 +460,22 @@ impl Tui {
     pub fn exit_raw_mode(&mut self) -> Result<(), Report<TuiError>> {
         if crossterm::terminal::is_raw_mode_enabled().change_context(TuiError)? {
             self.flush().change_context(TuiError)?;
+            if self.kbd_enhancement.unwrap_or(false) {
+                crossterm::execute!(self.writer_mut(), PopKeyboardEnhancementFlags)
+                    .change_context(TuiError)?;
+            }
             if self.paste {
-                crossterm::execute!(std::io::stderr(), DisableBracketedPaste)
+                crossterm::execute!(self.writer_mut(), DisableBracketedPaste)
                     .change_context(TuiError)?;
             }
             if self.mouse {
-                crossterm::execute!(std::io::stderr(), DisableMouseCapture)
+                crossterm::execute!(self.writer_mut(), DisableMouseCapture)
                     .change_context(TuiError)?;
             }
-            crossterm::execute!(std::io::stderr(), LeaveAlternateScreen, cursor::Show)
+            crossterm::execute!(self.writer_mut(), LeaveAlternateScreen, cursor::Show)
                 .change_context(TuiError)?;
             crossterm::terminal::disable_raw_mode().change_context(TuiError)?;
+            crate::init::panic_hook::set_backend_flags(false, false);
         }
         Ok(())
     }
@@ -333,38 +488,86 @@ impl Tui {
         self.cancellation_token.cancel();
     }
 
-    /// Suspends the TUI by exiting raw mode.
+    /// Suspends the TUI, handing control back to the shell, and resumes once it's foregrounded
+    /// again.
     ///
-    /// Exits raw mode and returns the terminal to a normal state. This allows
-    /// the terminal to be used for other purposes (e.g., shell commands).
-    /// Can be resumed by calling `resume()`.
+    /// Cancels the event loop, exits raw mode, and flushes the output writer so nothing queued is
+    /// lost, then (on Unix) raises `SIGTSTP`. That call blocks the calling thread for as long as the
+    /// process is actually stopped — job control delivers `SIGCONT` and wakes it back up, at
+    /// which point this re-enters raw mode, restarts the event loop, and sends a fresh
+    /// `Event::Init`/`Event::Resize` so the caller's next draw repaints a screen that a shell
+    /// command may have scribbled over. On Windows, which has no job-control signals, this just
+    /// round-trips through exiting and re-entering raw mode.
     ///
     /// # Errors
     ///
-    /// Returns an error if exiting raw mode fails.
-    ///
-    /// # Notes
-    ///
-    /// The commented-out signal handling code would allow sending SIGTSTP
-    /// on Unix systems to suspend the process, but this is currently disabled.
+    /// Returns an error if raising the signal or re-entering raw mode fails.
     pub fn suspend(&mut self) -> Result<(), Report<TuiError>> {
+        self.cancel();
         self.exit_raw_mode().change_context(TuiError)?;
-        // #[cfg(not(windows))]
-        // signal_hook::low_level::raise(signal_hook::consts::signal::SIGTSTP)
-        //     .change_context(TuiError)?;
-        Ok(())
+        self.writer_mut().flush().change_context(TuiError)?;
+
+        #[cfg(not(windows))]
+        signal_hook::low_level::raise(signal_hook::consts::signal::SIGTSTP)
+            .change_context(TuiError)?;
+
+        self.resume()
     }
 
-    /// Resumes the TUI by entering raw mode.
+    /// Resumes the TUI by entering raw mode, restarting the event loop, and sending a fresh
+    /// `Event::Init`/`Event::Resize`.
     ///
-    /// Re-enters raw mode and restores the alternate screen buffer after
-    /// the TUI has been suspended. Should be called after `suspend()`.
+    /// Called automatically by `suspend()` once the process is foregrounded again; exposed
+    /// separately for callers that tear down and restore the backend by other means (see
+    /// `resume_after_handoff`, which restarts the event loop itself instead).
     ///
     /// # Errors
     ///
-    /// Returns an error if entering raw mode fails.
+    /// Returns an error if entering raw mode, restarting the event loop, or sending the
+    /// follow-up events fails.
     pub fn resume(&mut self) -> Result<(), Report<TuiError>> {
         self.enter_raw_mode().change_context(TuiError)?;
+        self.start().change_context(TuiError)?;
+        let (width, height) = crossterm::terminal::size().change_context(TuiError)?;
+        self.event_tx
+            .send(Event::Init)
+            .change_context(TuiError)
+            .attach("failed to send Init event on resume")?;
+        self.event_tx
+            .send(Event::Resize(width, height))
+            .change_context(TuiError)
+            .attach("failed to send Resize event on resume")?;
+        Ok(())
+    }
+
+    /// Tears down only the draw surface and event-capture task for a temporary handoff to
+    /// another program (e.g. an external editor), without discarding the event channel.
+    ///
+    /// Unlike recreating a whole new `Tui` (which would drop `event_rx`/`event_tx` and
+    /// silently lose any events that were queued but not yet read), this keeps the same
+    /// channel alive across the handoff so `resume_after_handoff` can deliver them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if stopping the event loop or exiting raw mode fails.
+    pub fn teardown_for_handoff(&mut self) -> Result<(), Report<TuiError>> {
+        self.stop()?;
+        self.exit_raw_mode()?;
+        Ok(())
+    }
+
+    /// Re-enters raw mode and restarts event capture after `teardown_for_handoff`.
+    ///
+    /// Because `event_rx`/`event_tx` were never recreated, any keypresses that were queued
+    /// (sent by the capture task before it was stopped, but not yet consumed by the caller)
+    /// are still buffered in the channel and will be the first events returned by `next()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if entering raw mode or restarting the event loop fails.
+    pub fn resume_after_handoff(&mut self) -> Result<(), Report<TuiError>> {
+        self.enter_raw_mode()?;
+        self.start()?;
         Ok(())
     }
 
@@ -394,8 +597,8 @@ impl Tui {
 ///
 /// This implementation enables direct use of terminal methods on the `Tui` struct
 /// by automatically dereferencing to the internal terminal instance.
-impl Deref for Tui {
-    type Target = ratatui::Terminal<Backend<std::io::Stderr>>;
+impl<W: Write> Deref for Tui<W> {
+    type Target = ratatui::Terminal<Backend<W>>;
 
     fn deref(&self) -> &Self::Target {
         &self.terminal
@@ -406,7 +609,7 @@ impl Deref for Tui {
 ///
 /// This implementation enables direct use of mutable terminal methods on the `Tui`
 /// struct by automatically dereferencing to the internal terminal instance.
-impl DerefMut for Tui {
+impl<W: Write> DerefMut for Tui<W> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.terminal
     }
@@ -422,7 +625,7 @@ impl DerefMut for Tui {
 ///
 /// Panics if exiting raw mode fails during drop. This is a critical error
 /// that should not occur in normal circumstances.
-impl Drop for Tui {
+impl<W: Write> Drop for Tui<W> {
     fn drop(&mut self) {
         self.exit_raw_mode()
             .expect("failure while exiting TUI backend");
@@ -0,0 +1,96 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, List, ListItem},
+};
+
+use crate::{
+    App,
+    feat::{issue::Status, tui_widget::HelpPopup},
+};
+
+pub trait BoardDraw {
+    fn render(self, area: Rect, buf: &mut Buffer);
+}
+
+impl BoardDraw for &mut App {
+    /// Renders the kanban board: one bordered column per configured [`Status`], each listing the
+    /// issues currently in that status (oldest first), with the focused card highlighted and the
+    /// focused column's border bolded.
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let theme = self.config.theme.clone();
+
+        let mut columns: Vec<(Status, Vec<_>)> = Status::all()
+            .into_iter()
+            .map(|status| (status, Vec::new()))
+            .collect();
+        let mut issues: Vec<_> = self.issues.iter_issues().collect();
+        issues.sort_by_key(|issue| issue.created);
+        for issue in issues {
+            if let Some((_, column)) = columns
+                .iter_mut()
+                .find(|(status, _)| *status == issue.status)
+            {
+                column.push(issue.id);
+            }
+        }
+        self.tuistate.board.set_columns(columns.clone());
+
+        let constraints = vec![Constraint::Fill(1); columns.len().max(1)];
+        let column_areas = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(constraints)
+            .split(area);
+
+        let focused_column = self.tuistate.board.focused_column();
+        for (index, (status, issue_ids)) in columns.iter().enumerate() {
+            let Some(column_area) = column_areas.get(index) else {
+                continue;
+            };
+            let is_focused_column = index == focused_column;
+            let focused_row = self.tuistate.board.focused_row(index).unwrap_or(0);
+
+            let border_style = if is_focused_column {
+                Style::default().add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let block = Block::default()
+                .title(status.name())
+                .borders(Borders::ALL)
+                .border_style(border_style)
+                .style(Style::default().fg(theme.status_colors.color_for(*status)));
+            let inner_area = block.inner(*column_area);
+            block.render(*column_area, buf);
+
+            let items: Vec<ListItem> = issue_ids
+                .iter()
+                .enumerate()
+                .filter_map(|(row, issue_id)| {
+                    let issue = self.issues.get_issue(issue_id)?;
+                    let mut style =
+                        Style::default().fg(theme.priority_colors.color_for(issue.priority));
+                    if is_focused_column && row == focused_row {
+                        style = style.add_modifier(Modifier::REVERSED);
+                    }
+                    Some(ListItem::new(format!("#{} {}", issue.id, issue.title)).style(style))
+                })
+                .collect();
+            List::new(items).render(inner_area, buf);
+        }
+
+        if self.tuistate.board.show_help {
+            let items = vec![
+                ("<tab>", "Back to issue table"),
+                ("h/<left>", "Previous column"),
+                ("l/<right>", "Next column"),
+                ("j/<down>", "Cursor down"),
+                ("k/<up>", "Cursor up"),
+                ("<enter>", "Open thread"),
+                ("<alt>s", "Toggle status"),
+                ("?", "Toggle help"),
+            ];
+            let help_widget = HelpPopup::new(items).title("Hotkeys");
+            help_widget.render(*buf.area(), buf);
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use error_stack::{Report, ResultExt};
+use wherror::Error;
+
+use crate::{
+    App,
+    feat::{
+        issues::IssueEvent,
+        keymap::{Action, Context},
+        tui::{Event, EventExt, EventPropagation, Focus, Page},
+    },
+};
+
+/// Error type for board page input handling operations.
+///
+/// This error is returned when input handling operations fail, such as when recording a status
+/// change to the event log.
+#[derive(Debug, Error)]
+#[error(debug)]
+pub struct BoardPageInputError;
+
+/// Handles keyboard input events for the kanban board page.
+pub trait BoardPageInput {
+    /// Processes a keyboard input event for the board.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input handling operation fails, such as when recording a status
+    /// change to the event log.
+    fn handle(&mut self, event: &Event) -> Result<EventPropagation, Report<BoardPageInputError>>;
+}
+
+impl BoardPageInput for App {
+    /// When the board has focus, this resolves the incoming key event to an [`Action`] via
+    /// `self.config.keymap` (see the `Board` context) and dispatches on it with `apply_board`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if recording a status change to the event log fails.
+    fn handle(&mut self, event: &Event) -> Result<EventPropagation, Report<BoardPageInputError>> {
+        match self.tuistate.focus() {
+            Focus::Board => {
+                if let (Some(key), Some(mods)) = (event.keypress(), event.modifiers()) {
+                    if let Some(action) = self.config.keymap.resolve(Context::Board, key, mods) {
+                        return self.apply_board(action);
+                    }
+                }
+            }
+            _ => {
+                self.tuistate.set_focus(Focus::Board);
+                return Ok(EventPropagation::Stop);
+            }
+        }
+        Ok(EventPropagation::Continue)
+    }
+}
+
+impl App {
+    /// Dispatches a single [`Action`] resolved from the board's keymap.
+    ///
+    /// `Quit` and `Suspend` are deliberately not consumed here, for the same reason as
+    /// `IssueTablePageInput::apply`: they're bound in the `Board` context so they show up in a
+    /// keymap dump, but are only acted on by `App::handle_event`'s own top-level resolve.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if recording the `StatusChanged` event for `ToggleStatus` fails.
+    fn apply_board(
+        &mut self,
+        action: Action,
+    ) -> Result<EventPropagation, Report<BoardPageInputError>> {
+        match action {
+            Action::CursorNext => self.tuistate.board.cursor_next(),
+            Action::CursorPrev => self.tuistate.board.cursor_previous(),
+            Action::ColumnPageNext => self.tuistate.board.column_next(),
+            Action::ColumnPagePrevious => self.tuistate.board.column_previous(),
+            Action::ToggleHelp => self.tuistate.board.toggle_help(),
+            Action::ToggleBoard => {
+                self.tuistate.set_page(Page::IssueTable);
+                self.tuistate.set_focus(Focus::IssueTable);
+            }
+            Action::ToggleStatus => {
+                if let Some(issue_id) = self.tuistate.board.focused_issue() {
+                    let status = {
+                        let issue = self
+                            .issues
+                            .get_issue(&issue_id)
+                            .ok_or(BoardPageInputError)
+                            .attach("unable to find issue to toggle status")?;
+                        issue.status.cycle_next()
+                    };
+                    self.record_event(IssueEvent::StatusChanged { issue_id, status })
+                        .change_context(BoardPageInputError)?;
+                }
+            }
+            Action::OpenThread => {
+                if let Some(issue_id) = self.tuistate.board.focused_issue() {
+                    self.tuistate.issue_thread.set_issue_id(issue_id);
+                    self.tuistate.set_page(Page::IssueThread);
+                    self.tuistate.set_focus(Focus::IssueThread);
+                }
+            }
+            // Not bound to anything board-specific; `Quit`/`Suspend` are resolved by
+            // `App::handle_event`'s top-level keymap lookup, and the rest belong to other
+            // contexts.
+            _ => return Ok(EventPropagation::Continue),
+        }
+        Ok(EventPropagation::Stop)
+    }
+}
@@ -0,0 +1,7 @@
+mod draw;
+mod input;
+mod state;
+
+pub use draw::BoardDraw;
+pub use input::BoardPageInput;
+pub use state::BoardState;
@@ -0,0 +1,101 @@
+use crate::feat::issue::{IssueId, Status};
+
+/// State of the kanban board page: one column of issue ids per [`Status`], plus a focus cursor
+/// over them.
+///
+/// `columns` is rebuilt from scratch by `BoardDraw::render` on every render, so it always
+/// reflects the latest `Issues` projection rather than needing to be kept in sync by hand.
+#[derive(Debug, Default)]
+pub struct BoardState {
+    pub(in crate::feat::tui_board) columns: Vec<(Status, Vec<IssueId>)>,
+    /// Index into `columns` of the column the cursor is in.
+    pub(in crate::feat::tui_board) focused_column: usize,
+    /// Index into the focused column's issue list the cursor is on, one per column so moving
+    /// between columns remembers each one's row.
+    pub(in crate::feat::tui_board) focused_row: Vec<usize>,
+    pub(in crate::feat::tui_board) show_help: bool,
+}
+
+impl BoardState {
+    /// The board's columns as of the most recent render.
+    pub fn columns(&self) -> &[(Status, Vec<IssueId>)] {
+        &self.columns
+    }
+
+    /// Index into [`Self::columns`] of the column the cursor is in.
+    pub fn focused_column(&self) -> usize {
+        self.focused_column
+    }
+
+    /// The cursor's row within `column`, if that column exists.
+    pub fn focused_row(&self, column: usize) -> Option<usize> {
+        self.focused_row.get(column).copied()
+    }
+
+    /// Rebuilds the board's columns for the current render, clamping the focus cursor so it
+    /// still lands on a valid column/row after issues are created, reassigned to a different
+    /// status, or deleted.
+    pub fn set_columns(&mut self, columns: Vec<(Status, Vec<IssueId>)>) {
+        self.focused_row.resize(columns.len(), 0);
+        self.columns = columns;
+
+        if self.columns.is_empty() {
+            self.focused_column = 0;
+        } else {
+            self.focused_column = self.focused_column.min(self.columns.len() - 1);
+        }
+        for (index, (_, issues)) in self.columns.iter().enumerate() {
+            if let Some(row) = self.focused_row.get_mut(index) {
+                *row = if issues.is_empty() {
+                    0
+                } else {
+                    (*row).min(issues.len() - 1)
+                };
+            }
+        }
+    }
+
+    /// Moves the column cursor right by one, clamped to the last column.
+    pub fn column_next(&mut self) {
+        if self.columns.is_empty() {
+            return;
+        }
+        self.focused_column = (self.focused_column + 1).min(self.columns.len() - 1);
+    }
+
+    /// Moves the column cursor left by one, clamped to the first column.
+    pub fn column_previous(&mut self) {
+        self.focused_column = self.focused_column.saturating_sub(1);
+    }
+
+    /// Moves the row cursor down by one within the focused column, clamped to its last issue.
+    pub fn cursor_next(&mut self) {
+        let Some((_, issues)) = self.columns.get(self.focused_column) else {
+            return;
+        };
+        if issues.is_empty() {
+            return;
+        }
+        if let Some(row) = self.focused_row.get_mut(self.focused_column) {
+            *row = (*row + 1).min(issues.len() - 1);
+        }
+    }
+
+    /// Moves the row cursor up by one within the focused column, clamped to its first issue.
+    pub fn cursor_previous(&mut self) {
+        if let Some(row) = self.focused_row.get_mut(self.focused_column) {
+            *row = row.saturating_sub(1);
+        }
+    }
+
+    /// The issue under the cursor in the focused column, if any.
+    pub fn focused_issue(&self) -> Option<IssueId> {
+        let (_, issues) = self.columns.get(self.focused_column)?;
+        let row = *self.focused_row.get(self.focused_column)?;
+        issues.get(row).copied()
+    }
+
+    pub fn toggle_help(&mut self) {
+        self.show_help = !self.show_help;
+    }
+}
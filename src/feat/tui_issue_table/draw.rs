@@ -1,45 +1,69 @@
-use std::borrow::Cow;
+use std::ops::Range;
 
 use crate::{
     App,
     feat::{
-        tui_issue_table::{Column, SortDirection, apply_issue_filter, apply_issue_sort},
-        tui_widget::{HelpPopup, InputBox},
+        tui_issue_table::{
+            Column, ColumnAlign, SortDirection, apply_issue_filter, apply_issue_sort,
+            query_has_error, title_match_ranges, truncate_display,
+        },
+        tui_widget::{HelpPopup, InputBox, TextPopup},
     },
 };
 
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, Cell, Row, Table},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
 };
 
+/// Builds a title cell, bolding/underlining the byte ranges in `ranges` (see
+/// [`title_match_ranges`]). Falls back to a plain cell when there's nothing to highlight, e.g.
+/// when the filter box is empty or its query didn't match the title.
+fn title_cell(title: &str, ranges: &[Range<usize>], align: ColumnAlign) -> Cell<'_> {
+    if ranges.is_empty() {
+        return Cell::from(Line::from(title).alignment(align.into()));
+    }
+
+    let highlight = Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+    let mut spans = Vec::new();
+    let mut cursor = 0;
+    for range in ranges {
+        if range.start > cursor {
+            spans.push(Span::raw(&title[cursor..range.start]));
+        }
+        spans.push(Span::styled(&title[range.clone()], highlight));
+        cursor = range.end;
+    }
+    if cursor < title.len() {
+        spans.push(Span::raw(&title[cursor..]));
+    }
+    Cell::from(Line::from(spans).alignment(align.into()))
+}
+
+/// Builds a plain (non-title) cell from already-truncated `text`, applying `align`.
+fn display_cell(text: std::borrow::Cow<'_, str>, align: ColumnAlign) -> Cell<'static> {
+    Cell::from(Line::from(text.into_owned()).alignment(align.into()))
+}
+
 pub trait IssueTableDraw {
     fn render(self, area: Rect, buf: &mut Buffer);
 }
 impl IssueTableDraw for &mut App {
     #[allow(clippy::too_many_lines)]
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let (content_area, filter_area) = {
-            let block = Block::default().title("Issue List").borders(Borders::ALL);
-            let content_area = block.inner(area);
-            block.render(area, buf);
-
-            let layout = Layout::default()
-                .direction(Direction::Vertical)
-                .constraints([Constraint::Fill(1), Constraint::Length(1)])
-                .split(content_area);
-            (layout[0], layout[1])
+        let theme = self.config.theme.clone();
+        let filter_text = self.tuistate.issue_table.filter_input_state().text();
+        let prefix_fg = if query_has_error(&filter_text) {
+            theme.filter_error_fg
+        } else {
+            theme.filter_prefix_fg
         };
-
         let input_box = InputBox::default().with_prefix(vec![
-            Span::from("/").style(Style::default().fg(Color::Red)),
+            Span::from("/").style(Style::default().fg(prefix_fg.into())),
             Span::from(" Filter >> "),
         ]);
 
-        let mut filtered_issues = apply_issue_filter(
-            &self.tuistate.issue_table.filter_input_state().text(),
-            &self.issues,
-        );
+        let mut filtered_issues = apply_issue_filter(&filter_text, &self.issues);
 
         // Clamp table selection to filtered length
         let table_state = &mut self.tuistate.issue_table.table;
@@ -54,12 +78,87 @@ impl IssueTableDraw for &mut App {
             }
         }
 
-        let columns = &self.tuistate.issue_table.columns;
+        let all_columns = &self.tuistate.issue_table.columns;
+        let frozen: Vec<Column> = all_columns
+            .iter()
+            .filter(|c| c.is_frozen())
+            .cloned()
+            .collect();
+        let scrollable: Vec<Column> = all_columns
+            .iter()
+            .filter(|c| !c.is_frozen())
+            .cloned()
+            .collect();
+
+        // Horizontal column paging: `Id`/`Title` stay pinned; the rest scroll starting at
+        // `column_page`, fitting as many as the area's width (minus the frozen columns and
+        // border) allows.
+        let column_page = self
+            .tuistate
+            .issue_table
+            .column_page()
+            .min(scrollable.len().saturating_sub(1));
+        let frozen_width: u16 = frozen.iter().map(Column::paging_width).sum();
+        let available = area.width.saturating_sub(2).saturating_sub(frozen_width);
+        let mut visible_count = 0;
+        let mut used = 0u16;
+        for col in &scrollable[column_page..] {
+            let width = col.paging_width();
+            if visible_count > 0 && used + width > available {
+                break;
+            }
+            used += width;
+            visible_count += 1;
+        }
+        self.tuistate
+            .issue_table
+            .set_visible_column_count(visible_count);
+
+        let columns: Vec<Column> = frozen
+            .iter()
+            .cloned()
+            .chain(
+                scrollable[column_page..column_page + visible_count]
+                    .iter()
+                    .cloned(),
+            )
+            .collect();
+        let columns = &columns;
+
+        let (content_area, filter_area) = {
+            let title = if scrollable.len() > visible_count {
+                let window = visible_count.max(1);
+                let total_pages = scrollable.len().div_ceil(window);
+                format!(
+                    "Issue List ◀ {}/{} ▶",
+                    column_page / window + 1,
+                    total_pages
+                )
+            } else {
+                "Issue List".to_string()
+            };
+
+            let block = Block::default().title(title).borders(Borders::ALL);
+            let content_area = block.inner(area);
+            block.render(area, buf);
+
+            let layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Fill(1), Constraint::Length(1)])
+                .split(content_area);
+            (layout[0], layout[1])
+        };
 
         // Sort filtered issues
         let sort_col = &self.tuistate.issue_table.sort_by;
         let sort_dir = self.tuistate.issue_table.sort_direction;
-        apply_issue_sort(&mut filtered_issues, sort_col, sort_dir);
+        apply_issue_sort(
+            &mut filtered_issues,
+            sort_col,
+            sort_dir,
+            &filter_text,
+            self.config.custom_sort_missing,
+        );
 
         for (table_index, issue) in filtered_issues.iter().enumerate() {
             self.tuistate
@@ -70,11 +169,12 @@ impl IssueTableDraw for &mut App {
 
         // Header
         let header_style = Style::default()
-            .fg(Color::Yellow)
+            .fg(theme.header_fg.into())
+            .bg(theme.header_bg.into())
             .add_modifier(Modifier::BOLD);
         let selected_header_style = Style::default()
-            .fg(Color::White)
-            .bg(Color::DarkGray)
+            .fg(theme.selected_header_fg.into())
+            .bg(theme.selected_header_bg.into())
             .add_modifier(Modifier::BOLD);
         let header = Row::new(
             columns
@@ -97,51 +197,99 @@ impl IssueTableDraw for &mut App {
         );
 
         // Rows
+        let marked_style = Style::default()
+            .fg(theme.marked_row_fg.into())
+            .add_modifier(Modifier::BOLD);
         let rows: Vec<Row> = filtered_issues
             .iter()
             .map(|issue| {
-                Row::new(
+                let row = Row::new(
                     columns
                         .iter()
                         .map(|col| {
-                            let content: Cow<'_, str> = match col {
-                                Column::Id => issue.id.to_string().into(),
-                                Column::Title => issue.title.as_str().into(),
-                                Column::Created => {
-                                    issue.created.strftime("%FT%TZ").to_string().into()
+                            let display = self.tuistate.issue_table.column_display(col);
+                            match col {
+                                Column::Title => {
+                                    let ranges =
+                                        title_match_ranges(&filter_text, issue).unwrap_or_default();
+                                    title_cell(&issue.title, &ranges, display.align)
+                                }
+                                Column::Status => {
+                                    let text =
+                                        truncate_display(&col.cell_text(issue), display.max_width);
+                                    display_cell(text, display.align).style(
+                                        Style::default()
+                                            .fg(theme.status_colors.color_for(issue.status)),
+                                    )
+                                }
+                                Column::Priority => {
+                                    let text =
+                                        truncate_display(&col.cell_text(issue), display.max_width);
+                                    display_cell(text, display.align).style(
+                                        Style::default()
+                                            .fg(theme.priority_colors.color_for(issue.priority)),
+                                    )
+                                }
+                                _ => {
+                                    let text =
+                                        truncate_display(&col.cell_text(issue), display.max_width);
+                                    display_cell(text, display.align)
                                 }
-                                Column::Status => format!("{:?}", issue.status).into(),
-                                Column::Priority => format!("{:?}", issue.priority).into(),
-                                Column::CreatedBy => issue.created_by.as_str().into(),
-                                Column::Custom(key) => issue
-                                    .custom
-                                    .get(key.as_str())
-                                    .map_or(String::default().into(), Cow::from),
-                            };
-                            Cell::from(content)
+                            }
                         })
                         .collect::<Vec<_>>(),
-                )
+                );
+                if self.tuistate.issue_table.is_marked(issue.id) {
+                    row.style(marked_style)
+                } else {
+                    row
+                }
             })
             .collect();
 
-        // Constraints based on columns
+        // Constraints based on columns' configured display (`IssueTableState::column_display`),
+        // falling back to `Column::default_display`. `Title` always fills remaining space
+        // regardless of its configured widths, matching the paging math above.
         let constraints: Vec<Constraint> = columns
             .iter()
-            .map(|col| match col {
-                Column::Id => Constraint::Length(4),
-                Column::Title => Constraint::Fill(1),
-                Column::Created => Constraint::Length(20),
-                Column::Status => Constraint::Length(8),
-                Column::Priority => Constraint::Length(11),
-                Column::CreatedBy => Constraint::Length(30),
-                Column::Custom(_) => Constraint::Min(12),
+            .map(|col| {
+                if *col == Column::Title {
+                    return Constraint::Fill(1);
+                }
+                let display = self.tuistate.issue_table.column_display(col);
+                match display.max_width {
+                    Some(max_width) => Constraint::Length(max_width.max(display.min_width)),
+                    None => Constraint::Min(display.min_width),
+                }
             })
             .collect();
 
         let table = Table::new(rows, &constraints[..])
             .header(header)
-            .row_highlight_style(Style::new().reversed());
+            .row_highlight_style(
+                Style::default()
+                    .fg(theme.row_highlight_fg.into())
+                    .bg(theme.row_highlight_bg.into()),
+            );
+
+        // Record the rendered column bounds so mouse clicks can be hit-tested against them; see
+        // `IssueTableState::column_at`/`click_header`. Table lays out columns the same way a
+        // plain horizontal `Layout` with the same constraints and its default column spacing
+        // of 1 would.
+        let column_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(constraints.clone())
+            .spacing(1)
+            .split(content_area);
+        let column_bounds: Vec<(Column, u16, u16)> = columns
+            .iter()
+            .cloned()
+            .zip(column_layout.iter())
+            .map(|(col, rect)| (col, rect.x, rect.x + rect.width))
+            .collect();
+        self.tuistate
+            .issue_table
+            .set_table_geometry(content_area, column_bounds);
 
         StatefulWidget::render(table, content_area, buf, table_state);
 
@@ -152,6 +300,41 @@ impl IssueTableDraw for &mut App {
             self.tuistate.issue_table.filter_input_state_mut(),
         );
 
+        // A hook failure or an undo/redo confirmation surfaces here for one render, then is gone.
+        if let Some(message) = self.tuistate.take_status_message() {
+            let area = *buf.area();
+            let line_area = Rect {
+                x: area.x,
+                y: area.y + area.height.saturating_sub(1),
+                width: area.width,
+                height: 1,
+            };
+            Paragraph::new(format!(" {message} "))
+                .style(Style::default().fg(Color::Black).bg(Color::Yellow))
+                .render(line_area, buf);
+        }
+
+        if self.tuistate.issue_table.cell_inspect_open() {
+            if let Some(index) = self.tuistate.issue_table.selected().first().copied() {
+                if let Some(&issue_id) = self.tuistate.issue_table.display_map.get(&index) {
+                    if let Some(issue) = self.issues.get_issue(&issue_id) {
+                        let all_columns = self.tuistate.issue_table.columns();
+                        let col_index = self
+                            .tuistate
+                            .issue_table
+                            .inspect_column()
+                            .min(all_columns.len().saturating_sub(1));
+                        if let Some(column) = all_columns.get(col_index) {
+                            let content = column.cell_text(issue);
+                            let title = format!(" {column} — #{issue_id} ");
+                            let popup = TextPopup::new(&content).title(&title);
+                            popup.render(*buf.area(), buf);
+                        }
+                    }
+                }
+            }
+        }
+
         if self.tuistate.issue_table.show_help {
             let items = vec![
                 ("<shift>h", "Sort column left"),
@@ -163,9 +346,16 @@ impl IssueTableDraw for &mut App {
                 ("k", "Cursor up"),
                 ("l", "Cursor right"),
                 ("c", "Change columns"),
-                ("s", "Toggle issue status"),
+                ("s", "Toggle status (marked)"),
+                ("p", "Bump priority (marked)"),
+                ("v/<space>", "Mark row"),
+                ("g", "Sync git history"),
+                ("i", "Inspect cell"),
                 ("/", "Filter"),
                 ("n", "New issue"),
+                ("u", "Undo"),
+                ("<ctrl>r", "Redo"),
+                ("<tab>", "Toggle board view"),
                 ("?", "Show help"),
             ];
             let help_widget = HelpPopup::new(items).title("Hotkeys");
@@ -0,0 +1,499 @@
+use std::{ops::Range, str::FromStr};
+
+use jiff::{Span, Timestamp};
+
+use super::fuzzy::{fuzzy_match, fuzzy_score};
+use crate::feat::{
+    issue::{Issue, Priority, Status},
+    issues::Issues,
+};
+
+/// Relational comparator for a field-scoped filter term.
+///
+/// `status` (ordered by its position in the configured status set), `priority` and `created`
+/// apply every comparator. `created_by` and custom fields ignore the comparator and always do a
+/// case-insensitive substring match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparator {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// The field a [`FieldTerm`] is scoped to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Field {
+    Status,
+    Priority,
+    CreatedBy,
+    Created,
+    /// A key into `Issue.custom`, e.g. `assigned_to:bob`.
+    Custom(String),
+}
+
+/// A single `field:value` term, e.g. `priority:>=high` or `created:<7d`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldTerm {
+    pub field: Field,
+    pub cmp: Comparator,
+    pub value: String,
+}
+
+/// One node of the parsed filter query.
+///
+/// A query parses into a small tree of these: bare words become [`Filter::Title`], `field:value`
+/// tokens become [`Filter::Field`], a leading `-` wraps a term in [`Filter::Not`], terms within
+/// one `OR`-separated group are implicitly `AND`ed via [`Filter::And`], and multiple groups
+/// combine via [`Filter::Or`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Filter {
+    /// fzf-style fuzzy subsequence match against the issue title (falling back to the
+    /// concatenated custom field values when the title itself doesn't match).
+    Title(String),
+    Field(FieldTerm),
+    Not(Box<Filter>),
+    And(Vec<Filter>),
+    Or(Vec<Filter>),
+}
+
+/// Splits query text into tokens, keeping `"quoted phrases"` together as a single token.
+fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    let mut current = String::new();
+    let mut in_quotes = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            c if c.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+/// Parses the comparator prefix off a field value, e.g. `">=high"` -> `(Ge, "high")`.
+fn parse_comparator(value: &str) -> (Comparator, &str) {
+    if let Some(rest) = value.strip_prefix(">=") {
+        (Comparator::Ge, rest)
+    } else if let Some(rest) = value.strip_prefix("<=") {
+        (Comparator::Le, rest)
+    } else if let Some(rest) = value.strip_prefix('>') {
+        (Comparator::Gt, rest)
+    } else if let Some(rest) = value.strip_prefix('<') {
+        (Comparator::Lt, rest)
+    } else if let Some(rest) = value.strip_prefix('=') {
+        (Comparator::Eq, rest)
+    } else {
+        (Comparator::Eq, value)
+    }
+}
+
+fn field_from_name(name: &str) -> Field {
+    match name.to_lowercase().as_str() {
+        "status" => Field::Status,
+        "priority" => Field::Priority,
+        "created_by" | "createdby" | "author" => Field::CreatedBy,
+        "created" | "date" => Field::Created,
+        custom => Field::Custom(custom.to_string()),
+    }
+}
+
+/// Parses a single token (already unquoted of its leading negation prefix) into a [`Filter`].
+///
+/// A `|` inside the token (or inside a `field:` term's value) splits it into alternatives
+/// combined with [`Filter::Or`], e.g. `status:open|closed` or a bare `bug|crash`.
+///
+/// Backward-compat: the bare words `open`/`closed` (no `field:` prefix) resolve to
+/// `status:open`/`status:closed` rather than a literal title match. This preserves the meaning
+/// of the pre-chunk3-6 magic tokens `!open`/`!closed` this grammar replaces, so the default
+/// filter text `!closed` keeps hiding closed issues instead of title-matching the literal
+/// characters `!closed`.
+fn parse_term(token: &str) -> Filter {
+    if let Some((name, value)) = token.split_once(':') {
+        let field = field_from_name(name);
+        if value.contains('|') {
+            return Filter::Or(
+                value
+                    .split('|')
+                    .map(|alt| {
+                        let (cmp, alt) = parse_comparator(alt);
+                        Filter::Field(FieldTerm {
+                            field: field.clone(),
+                            cmp,
+                            value: alt.to_string(),
+                        })
+                    })
+                    .collect(),
+            );
+        }
+        let (cmp, value) = parse_comparator(value);
+        Filter::Field(FieldTerm {
+            field,
+            cmp,
+            value: value.to_string(),
+        })
+    } else if token.contains('|') {
+        Filter::Or(token.split('|').map(parse_term).collect())
+    } else if token.eq_ignore_ascii_case("open") || token.eq_ignore_ascii_case("closed") {
+        Filter::Field(FieldTerm {
+            field: Field::Status,
+            cmp: Comparator::Eq,
+            value: token.to_lowercase(),
+        })
+    } else {
+        Filter::Title(token.to_lowercase())
+    }
+}
+
+/// Strips a leading negation prefix (`-` or `!`) off `token`, returning whether it was negated
+/// and the remaining text. A bare `-`/`!` with nothing after it is left alone (not treated as
+/// negation of an empty term).
+fn strip_negation(token: &str) -> (bool, &str) {
+    for prefix in ['-', '!'] {
+        if let Some(rest) = token.strip_prefix(prefix)
+            && !rest.is_empty()
+        {
+            return (true, rest);
+        }
+    }
+    (false, token)
+}
+
+/// Parses free-form filter text into a [`Filter`] tree.
+///
+/// Terms are implicitly `AND`ed together. The literal keyword `OR` splits the query into
+/// alternative groups, each of which is evaluated independently and combined with `OR`; `|`
+/// inside a single term is a more compact alternative (see [`parse_term`]). A leading `-` or `!`
+/// on any term negates it. Quoted phrases (`"foo bar"`) are kept as one term.
+///
+/// Returns `None` for empty or all-whitespace input, meaning "match everything".
+pub fn parse_filter(text: &str) -> Option<Filter> {
+    let tokens = tokenize(text);
+    if tokens.is_empty() {
+        return None;
+    }
+
+    let mut groups: Vec<Vec<String>> = vec![Vec::new()];
+    for token in tokens {
+        if token == "OR" {
+            groups.push(Vec::new());
+        } else {
+            groups.last_mut().unwrap().push(token);
+        }
+    }
+
+    let mut or_terms = Vec::new();
+    for group in groups {
+        let mut and_terms = Vec::new();
+        for token in group {
+            let (negate, token) = strip_negation(&token);
+            let filter = parse_term(token);
+            and_terms.push(if negate {
+                Filter::Not(Box::new(filter))
+            } else {
+                filter
+            });
+        }
+        if !and_terms.is_empty() {
+            or_terms.push(if and_terms.len() == 1 {
+                and_terms.into_iter().next().unwrap()
+            } else {
+                Filter::And(and_terms)
+            });
+        }
+    }
+
+    match or_terms.len() {
+        0 => None,
+        1 => or_terms.into_iter().next(),
+        _ => Some(Filter::Or(or_terms)),
+    }
+}
+
+/// Whether `text` fails to parse cleanly as filter query (currently: an unterminated quote), for
+/// the TUI query bar to render a subtle error style on the filter prefix. A failing query still
+/// evaluates via `apply_issue_filter` (the unterminated quote runs to the end of the text, making
+/// the rest one term) rather than hard-erroring the whole table; this only drives the visual hint.
+pub fn query_has_error(text: &str) -> bool {
+    text.chars().filter(|&c| c == '"').count() % 2 != 0
+}
+
+/// Resolves a `created:` value into an absolute timestamp, supporting both RFC 3339 dates
+/// (`2024-01-01`) and relative durations measured back from now (`7d`, `2w`, `12h`).
+fn resolve_timestamp(value: &str) -> Option<Timestamp> {
+    if let Some(span) = parse_relative_duration(value) {
+        return Timestamp::now().checked_sub(span).ok();
+    }
+    value
+        .parse::<Timestamp>()
+        .ok()
+        .or_else(|| format!("{value}T00:00:00Z").parse::<Timestamp>().ok())
+}
+
+/// Parses a relative duration like `7d`, `2w`, `12h`, or `30m` into a [`Span`].
+fn parse_relative_duration(value: &str) -> Option<Span> {
+    let value = value.trim();
+    let unit = value.chars().last()?;
+    let amount: i64 = value[..value.len() - 1].parse().ok()?;
+    match unit {
+        'd' => Some(Span::new().days(amount)),
+        'w' => Some(Span::new().weeks(amount)),
+        'h' => Some(Span::new().hours(amount)),
+        'm' => Some(Span::new().minutes(amount)),
+        _ => None,
+    }
+}
+
+fn compare<T: PartialOrd>(cmp: Comparator, lhs: &T, rhs: &T) -> bool {
+    match cmp {
+        Comparator::Eq => lhs == rhs,
+        Comparator::Ne => lhs != rhs,
+        Comparator::Lt => lhs < rhs,
+        Comparator::Le => lhs <= rhs,
+        Comparator::Gt => lhs > rhs,
+        Comparator::Ge => lhs >= rhs,
+    }
+}
+
+fn eval_field(term: &FieldTerm, issue: &Issue) -> bool {
+    match &term.field {
+        Field::Status => match Status::from_str(&term.value) {
+            Ok(status) => compare(term.cmp, &issue.status, &status),
+            Err(_) => false,
+        },
+        Field::Priority => match Priority::from_str(&term.value) {
+            Ok(priority) => compare(term.cmp, &issue.priority, &priority),
+            Err(_) => false,
+        },
+        Field::CreatedBy => issue
+            .created_by
+            .to_lowercase()
+            .contains(&term.value.to_lowercase()),
+        Field::Created => match resolve_timestamp(&term.value) {
+            Some(ts) => compare(term.cmp, &issue.created, &ts),
+            None => false,
+        },
+        Field::Custom(key) => match issue.custom.get(key.as_str()) {
+            Some(actual) => actual.to_lowercase().contains(&term.value.to_lowercase()),
+            None => false,
+        },
+    }
+}
+
+/// Scores `query` against `issue` for fuzzy ranking: the title is tried first, falling back to
+/// the concatenated custom field values if the title itself isn't a subsequence match.
+///
+/// Returns `None` if neither candidate matches.
+pub(super) fn best_match_score(query: &str, issue: &Issue) -> Option<i64> {
+    fuzzy_score(query, &issue.title.to_lowercase()).or_else(|| {
+        let custom = issue
+            .custom
+            .values()
+            .map(|v| v.to_lowercase())
+            .collect::<Vec<_>>()
+            .join(" ");
+        fuzzy_score(query, &custom)
+    })
+}
+
+/// Extracts the plain (non field-scoped) query text from `filter_text`, for use as the fuzzy
+/// ranking key. `OR` groups, field-scoped terms (`status:open`), `|` alternations, the legacy
+/// `open`/`closed` status tokens, and negation prefixes are dropped; only the bare words a user
+/// would expect to rank titles against remain.
+///
+/// Returns `None` when there's no plain text left to rank by.
+pub fn title_query_text(filter_text: &str) -> Option<String> {
+    let words: Vec<String> = tokenize(filter_text)
+        .into_iter()
+        .filter(|token| token != "OR")
+        .map(|token| strip_negation(&token).1.to_string())
+        .filter(|token| !token.contains(':') && !token.contains('|'))
+        .filter(|token| {
+            !token.eq_ignore_ascii_case("open") && !token.eq_ignore_ascii_case("closed")
+        })
+        .map(|token| token.to_lowercase())
+        .collect();
+    if words.is_empty() {
+        None
+    } else {
+        Some(words.join(" "))
+    }
+}
+
+/// The byte ranges within `issue.title` that `filter_text`'s plain query text fuzzy-matched, for
+/// the table renderer to bold/underline. Unlike [`best_match_score`], this only looks at the
+/// title itself (not the custom-field fallback), since that's the only column the renderer
+/// highlights.
+///
+/// Returns `None` when `filter_text` has no plain query text, or when the title doesn't match it.
+pub fn title_match_ranges(filter_text: &str, issue: &Issue) -> Option<Vec<Range<usize>>> {
+    let query = title_query_text(filter_text)?;
+    fuzzy_match(&query, &issue.title).map(|(_, ranges)| ranges)
+}
+
+/// Evaluates a parsed [`Filter`] against a single issue. Visible to sibling modules (e.g.
+/// `state`'s tests) so they can exercise the filter grammar without going through
+/// `apply_issue_filter`'s `Issues` collection.
+pub(in crate::feat::tui_issue_table) fn eval(filter: &Filter, issue: &Issue) -> bool {
+    match filter {
+        Filter::Title(text) => best_match_score(text, issue).is_some(),
+        Filter::Field(term) => eval_field(term, issue),
+        Filter::Not(inner) => !eval(inner, issue),
+        Filter::And(terms) => terms.iter().all(|term| eval(term, issue)),
+        Filter::Or(terms) => terms.iter().any(|term| eval(term, issue)),
+    }
+}
+
+/// Parses `filter_text` into a [`Filter`] and evaluates it against every issue, returning the
+/// matches. Shared by the TUI query bar so the table view and any future CLI filtering use the
+/// same query language.
+pub fn apply_issue_filter<'a>(filter_text: &str, issues: &'a Issues) -> Vec<&'a Issue> {
+    match parse_filter(filter_text) {
+        None => issues.iter_issues().collect(),
+        Some(filter) => issues
+            .iter_issues()
+            .filter(|issue| eval(&filter, issue))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn issue(title: &str, status: Status, priority: Priority, created_by: &str) -> Issue {
+        Issue {
+            id: 1,
+            title: title.to_string(),
+            created: Timestamp::now(),
+            status,
+            priority,
+            created_by: created_by.to_string(),
+            custom: std::collections::HashMap::from([(
+                "assigned_to".to_string(),
+                "bob".to_string(),
+            )]),
+        }
+    }
+
+    #[rstest]
+    #[case("status:open", Status::open(), Priority::Low, true)]
+    #[case("status:open", Status::closed(), Priority::Low, false)]
+    #[case("-status:open", Status::closed(), Priority::Low, true)]
+    #[case("priority:>=high", Priority::Critical, Priority::Critical, true)]
+    fn matches_status_and_priority(
+        #[case] query: &str,
+        #[case] status: Status,
+        #[case] priority: Priority,
+        #[case] expected: bool,
+    ) {
+        let issue = issue("Sample", status, priority, "alice");
+        let filter = parse_filter(query).unwrap();
+        assert_eq!(eval(&filter, &issue), expected);
+    }
+
+    #[test]
+    fn matches_custom_field() {
+        let issue = issue("Sample", Status::open(), Priority::Low, "alice");
+        let filter = parse_filter("assigned_to:bob").unwrap();
+        assert!(eval(&filter, &issue));
+    }
+
+    #[test]
+    fn implicit_and_requires_all_terms() {
+        let issue = issue("Login crash", Status::open(), Priority::High, "alice");
+        assert!(eval(
+            &parse_filter("login created_by:alice").unwrap(),
+            &issue
+        ));
+        assert!(!eval(
+            &parse_filter("login created_by:bob").unwrap(),
+            &issue
+        ));
+    }
+
+    #[test]
+    fn or_combines_groups() {
+        let issue = issue("Login crash", Status::closed(), Priority::High, "alice");
+        let filter = parse_filter("status:open OR status:closed").unwrap();
+        assert!(eval(&filter, &issue));
+    }
+
+    #[test]
+    fn quoted_phrase_kept_as_one_token() {
+        let issue = issue("Login crash report", Status::open(), Priority::Low, "alice");
+        let filter = parse_filter(r#""login crash""#).unwrap();
+        assert!(eval(&filter, &issue));
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        assert!(parse_filter("").is_none());
+        assert!(parse_filter("   ").is_none());
+    }
+
+    #[rstest]
+    #[case("!closed", Status::closed(), false)]
+    #[case("!closed", Status::open(), true)]
+    #[case("!open", Status::open(), false)]
+    #[case("!open", Status::closed(), true)]
+    fn legacy_bang_status_tokens_still_work(
+        #[case] query: &str,
+        #[case] status: Status,
+        #[case] expected: bool,
+    ) {
+        let issue = issue("Sample", status, Priority::Low, "alice");
+        let filter = parse_filter(query).unwrap();
+        assert_eq!(eval(&filter, &issue), expected);
+    }
+
+    #[test]
+    fn bang_and_dash_negation_are_equivalent() {
+        let issue = issue("Sample", Status::closed(), Priority::Low, "alice");
+        assert_eq!(
+            eval(&parse_filter("!status:open").unwrap(), &issue),
+            eval(&parse_filter("-status:open").unwrap(), &issue)
+        );
+    }
+
+    #[test]
+    fn pipe_alternates_within_a_field_term() {
+        let open = issue("Sample", Status::open(), Priority::Low, "alice");
+        let closed = issue("Sample", Status::closed(), Priority::Low, "alice");
+        let filter = parse_filter("status:open|closed").unwrap();
+        assert!(eval(&filter, &open));
+        assert!(eval(&filter, &closed));
+    }
+
+    #[test]
+    fn pipe_alternates_bare_words() {
+        let bug = issue("bug report", Status::open(), Priority::Low, "alice");
+        let crash = issue("crash report", Status::open(), Priority::Low, "alice");
+        let other = issue("feature request", Status::open(), Priority::Low, "alice");
+        let filter = parse_filter("bug|crash").unwrap();
+        assert!(eval(&filter, &bug));
+        assert!(eval(&filter, &crash));
+        assert!(!eval(&filter, &other));
+    }
+
+    #[rstest]
+    #[case(r#"unterminated "quote"#, true)]
+    #[case(r#""balanced quote""#, false)]
+    #[case("status:open", false)]
+    fn query_has_error_detects_unterminated_quotes(#[case] query: &str, #[case] expected: bool) {
+        assert_eq!(query_has_error(query), expected);
+    }
+}
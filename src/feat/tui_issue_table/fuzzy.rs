@@ -0,0 +1,205 @@
+use std::ops::Range;
+
+const BASE_SCORE: i64 = 16;
+const CONSECUTIVE_BONUS: i64 = 8;
+const BOUNDARY_BONUS: i64 = 12;
+const GAP_PENALTY: i64 = 1;
+const UNREACHABLE: i64 = i64::MIN / 4;
+
+fn chars_eq(a: char, b: char) -> bool {
+    a.to_lowercase().eq(b.to_lowercase())
+}
+
+fn is_separator(c: char) -> bool {
+    matches!(c, ' ' | '-' | '_' | '/')
+}
+
+/// Whether `next` starts a new camelCase word coming right after `prev`.
+fn is_camel_boundary(prev: char, next: char) -> bool {
+    prev.is_lowercase() && next.is_uppercase()
+}
+
+fn is_subsequence(query: &[char], candidate: &[char]) -> bool {
+    let mut candidate_iter = candidate.iter();
+    query
+        .iter()
+        .all(|q| candidate_iter.any(|c| chars_eq(*q, *c)))
+}
+
+/// Computes an fzf-style subsequence match score between `query` and `candidate`.
+///
+/// Returns `None` if `query` is not a (case-insensitive) subsequence of `candidate`. See
+/// [`fuzzy_match`] for how the score is computed; this is a thin wrapper around it for callers
+/// that only need the score, not the matched ranges.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    fuzzy_match(query, candidate).map(|(score, _)| score)
+}
+
+/// Like [`fuzzy_score`], but also returns the byte ranges in `candidate` that the match covered,
+/// in ascending order with adjacent characters merged into a single range. Intended for a table
+/// renderer to bold/underline the matched characters; ranges are reported against `candidate`'s
+/// original text (matching is case-insensitive, but the positions it reports are not case-folded).
+///
+/// Runs a Smith-Waterman-style DP over `candidate`'s characters: each matched character earns a
+/// base score, a consecutive-match bonus is added when the previous query character matched the
+/// immediately preceding candidate character, and a boundary bonus is added when the match lands
+/// at the start of `candidate`, right after a separator (` `, `-`, `_`, `/`), or at a camelCase
+/// transition. Skipped candidate characters between two matches cost a gap penalty. Higher scores
+/// rank better; the empty query matches everything with a score of `0` and no ranges.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<(i64, Vec<Range<usize>>)> {
+    let query: Vec<char> = query.chars().collect();
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+    if !is_subsequence(&query, &candidate_chars) {
+        return None;
+    }
+
+    let n = query.len();
+    let m = candidate_chars.len();
+
+    // best[i][j] = best score achievable matching query[..i] using only candidate[..=j],
+    // not necessarily ending the match exactly at position j.
+    let mut best = vec![vec![0_i64; m + 1]; n + 1];
+    // ends[i][j] = best score matching query[..i] with query[i - 1] matched exactly at candidate
+    // position j (1-indexed), or `UNREACHABLE` if it can't match there.
+    let mut ends = vec![vec![UNREACHABLE; m + 1]; n + 1];
+    // best_end[i][j] = the candidate position (1-indexed) query[i - 1] matched at, to achieve
+    // best[i][j]; match_k[i][p] = the prior endpoint `k` that match was built on. Together these
+    // let the optimal chain of match positions be recovered by walking backward from (n, m).
+    let mut best_end = vec![vec![0_usize; m + 1]; n + 1];
+    let mut match_k = vec![vec![0_usize; m + 1]; n + 1];
+
+    for i in 1..=n {
+        best[i][0] = UNREACHABLE;
+        let mut running_best = UNREACHABLE;
+        let mut running_best_end = 0;
+        for j in 1..=m {
+            let mut matched_here = UNREACHABLE;
+            if chars_eq(candidate_chars[j - 1], query[i - 1]) {
+                let at_boundary = j == 1
+                    || is_separator(candidate_chars[j - 2])
+                    || is_camel_boundary(candidate_chars[j - 2], candidate_chars[j - 1]);
+                let boundary_bonus = if at_boundary { BOUNDARY_BONUS } else { 0 };
+
+                if i == 1 {
+                    matched_here = BASE_SCORE + boundary_bonus;
+                }
+                // Try every prior endpoint `k` the previous query char could have matched at,
+                // penalizing the gap between it and this match; `gap == 0` is a consecutive match.
+                for k in (i - 1)..=(j - 1) {
+                    if ends[i - 1][k] <= UNREACHABLE {
+                        continue;
+                    }
+                    let gap = (j - 1 - k) as i64;
+                    let consecutive_bonus = if gap == 0 { CONSECUTIVE_BONUS } else { 0 };
+                    let score = ends[i - 1][k] + BASE_SCORE + boundary_bonus - GAP_PENALTY * gap
+                        + consecutive_bonus;
+                    if score > matched_here {
+                        matched_here = score;
+                        match_k[i][j] = k;
+                    }
+                }
+            }
+            ends[i][j] = matched_here;
+            if matched_here > running_best {
+                running_best = matched_here;
+                running_best_end = j;
+            }
+            best[i][j] = running_best;
+            best_end[i][j] = running_best_end;
+        }
+    }
+
+    let score = best[n][m];
+    if score <= UNREACHABLE {
+        return None;
+    }
+
+    let mut positions = Vec::with_capacity(n);
+    let mut p = best_end[n][m];
+    for i in (1..=n).rev() {
+        positions.push(p - 1);
+        p = match_k[i][p];
+    }
+    positions.reverse();
+
+    let char_spans: Vec<Range<usize>> = candidate
+        .char_indices()
+        .map(|(start, c)| start..start + c.len_utf8())
+        .collect();
+    let mut ranges: Vec<Range<usize>> = Vec::with_capacity(positions.len());
+    for position in positions {
+        let span = char_spans[position].clone();
+        match ranges.last_mut() {
+            Some(last) if last.end == span.start => last.end = span.end,
+            _ => ranges.push(span),
+        }
+    }
+
+    Some((score, ranges))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("abc", "abc")]
+    #[case("abc", "a1b2c3")]
+    #[case("login", "Login crash on startup")]
+    #[case("lcs", "Login crash on startup")]
+    fn matches_subsequence(#[case] query: &str, #[case] candidate: &str) {
+        assert!(fuzzy_score(query, candidate).is_some());
+    }
+
+    #[rstest]
+    #[case("xyz", "abc")]
+    #[case("cba", "abc")]
+    fn rejects_non_subsequence(#[case] query: &str, #[case] candidate: &str) {
+        assert!(fuzzy_score(query, candidate).is_none());
+    }
+
+    #[test]
+    fn contiguous_match_outscores_scattered_match() {
+        let contiguous = fuzzy_score("log", "login crash").unwrap();
+        let scattered = fuzzy_score("log", "l a t e r o g").unwrap();
+        assert!(contiguous > scattered);
+    }
+
+    #[test]
+    fn word_boundary_match_outscores_mid_word_match() {
+        let boundary = fuzzy_score("crash", "login crash report").unwrap();
+        let mid_word = fuzzy_score("rash", "login crash report").unwrap();
+        assert!(boundary > mid_word);
+    }
+
+    #[test]
+    fn empty_query_matches_with_zero_score() {
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn contiguous_match_yields_a_single_range() {
+        let (_, ranges) = fuzzy_match("log", "login crash").unwrap();
+        assert_eq!(ranges, vec![0..3]);
+    }
+
+    #[test]
+    fn scattered_match_yields_one_range_per_character() {
+        let (_, ranges) = fuzzy_match("lcs", "Login Crash Startup").unwrap();
+        let matched: String = ranges
+            .iter()
+            .map(|r| &"Login Crash Startup"[r.clone()])
+            .collect();
+        assert_eq!(matched.to_lowercase(), "lcs");
+    }
+
+    #[test]
+    fn empty_query_yields_no_ranges() {
+        assert_eq!(fuzzy_match("", "anything"), Some((0, Vec::new())));
+    }
+}
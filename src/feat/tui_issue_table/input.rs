@@ -5,13 +5,34 @@ use crate::{
     App,
     feat::{
         external_editor::ExternalEditorError,
-        issue::Issue,
+        git_link,
+        issue::{Issue, IssueId},
         issues::IssueEvent,
-        tui::{Event, EventExt, EventPropagation, Focus, KeyCode, KeyModifiers, Page},
+        keymap::{Action, Context},
+        tui::{
+            Event, EventExt, EventPropagation, Focus, KeyCode, MouseButton, MouseEvent,
+            MouseEventKind, Page,
+        },
         tui_issue_table::{IssueTableState, SortDirection},
     },
 };
 
+/// The issues a bulk action (toggle status, bump priority) should apply to: every marked issue,
+/// or, if none are marked, just the cursor row.
+fn bulk_target_ids(issue_table: &IssueTableState) -> Vec<IssueId> {
+    let marked = issue_table.marked();
+    if !marked.is_empty() {
+        return marked.iter().copied().collect();
+    }
+    issue_table
+        .selected()
+        .first()
+        .and_then(|index| issue_table.display_map.get(index))
+        .copied()
+        .into_iter()
+        .collect()
+}
+
 /// Error type for issue table page input handling operations.
 ///
 /// This error is emitted when input handling operations fail, such as
@@ -44,178 +65,56 @@ pub trait IssueTablePageInput {
 impl IssueTablePageInput for App {
     /// Handles keyboard input events for the application's issue table.
     ///
-    /// This implementation manages keyboard shortcuts for the issue table interface,
-    /// including sorting, filtering, navigation, and issue status management.
+    /// When the issue table has focus, this resolves the incoming key event to an [`Action`] via
+    /// `self.config.keymap` (see the `IssueTable` context) and dispatches on it with `apply`.
+    /// While the cell-inspection popup is open, `h`/`l`/Left/Right and `Esc` are handled directly
+    /// instead (they move which column is inspected rather than whatever they're bound to), since
+    /// that's a transient sub-mode rather than a rebindable top-level shortcut. Mouse events are
+    /// handled the same way, by `handle_table_mouse`, since (unlike key presses) they're tied to
+    /// screen coordinates rather than a rebindable action.
     ///
     /// The handler operates differently based on the current UI focus:
-    /// - When focused on the issue table, it handles sorting, navigation, and editing commands
+    /// - When focused on the issue table, it resolves and dispatches the bound action
     /// - When focused on the filter input, it delegates events to the filter input handler
     /// - For other focus states, it returns focus to the issue table
     ///
-    /// # Key Bindings
-    ///
-    /// - **Alt+C**: Open external editor to modify column configuration
-    /// - **Shift+J**: Sort table in descending order
-    /// - **Shift+K**: Sort table in ascending order
-    /// - **Shift+L**: Sort by next column
-    /// - **Shift+H**: Sort by previous column
-    /// - **Alt+S**: Toggle status for selected issues
-    /// - **Down/J**: Move cursor to next item
-    /// - **Up/K**: Move cursor to previous item
-    /// - **/**: Focus the search filter input box
-    ///
     /// # Errors
     ///
     /// Returns an error when failing to log issue events to the event log
-    /// (specifically when toggling issue status).
+    /// (specifically when toggling issue status or bumping priority), or when a git history
+    /// scan fails.
     #[allow(clippy::too_many_lines)]
-    #[allow(clippy::match_wildcard_for_single_variants)]
     fn handle(
         &mut self,
         event: &Event,
     ) -> Result<EventPropagation, Report<IssueTablePageInputError>> {
         match self.tuistate.focus() {
             Focus::IssueTable => {
-                if let (Some(key), mods) = (event.keypress(), event.modifiers()) {
-                    match (key, mods) {
-                        // Edit columns
-                        (KeyCode::Char('c'), _) => {
-                            let columns = &self.tuistate.issue_table.columns;
-                            let columns = IssueTableState::available_columns_for_editing(columns);
-                            self.external_editor.edit(columns, "", |app, response| {
-                                if let Some(columns) = response {
-                                    let columns = IssueTableState::columns_from_edited(&columns);
-                                    app.tuistate.issue_table.set_columns(columns);
-                                }
-                                Ok(())
-                            });
-                            return Ok(EventPropagation::Stop);
-                        }
-                        // Create new issue
-                        (KeyCode::Char('n'), _) => {
-                            self.external_editor.edit(
-                                Issue::new_template(),
-                                "",
-                                move |app, response| {
-                                    if let Some(issue) = response {
-                                        let next_id = app.issues.next_issue_id();
-                                        let issue = Issue::from_str(next_id, issue)
-                                            .change_context(ExternalEditorError)?;
-                                        if let Some((issue, comment)) = issue {
-                                            app.issues
-                                                .append_to_log(&app.args.event_log, issue)
-                                                .change_context(ExternalEditorError)?;
-                                            app.issues
-                                                .append_to_log(&app.args.event_log, comment)
-                                                .change_context(ExternalEditorError)?;
-                                        }
-                                    }
-                                    Ok(())
-                                },
-                            );
-                            return Ok(EventPropagation::Stop);
-                        }
-                        // Sort descending
-                        (KeyCode::Down | KeyCode::Char('J' | 'j'), Some(mods))
-                            if mods.contains(KeyModifiers::SHIFT) =>
-                        {
-                            self.tuistate
-                                .issue_table
-                                .set_sort_direction(SortDirection::Descending);
-                            return Ok(EventPropagation::Stop);
-                        }
-                        // Sort ascending
-                        (KeyCode::Up | KeyCode::Char('K' | 'k'), Some(mods))
-                            if mods.contains(KeyModifiers::SHIFT) =>
-                        {
-                            self.tuistate
-                                .issue_table
-                                .set_sort_direction(SortDirection::Ascending);
-                            return Ok(EventPropagation::Stop);
-                        }
-                        // Sort next column
-                        (KeyCode::Right | KeyCode::Char('L' | 'l'), Some(mods))
-                            if mods.contains(KeyModifiers::SHIFT) =>
-                        {
-                            self.tuistate.issue_table.sort_next_column();
-                            return Ok(EventPropagation::Stop);
-                        }
-                        // Sort previous column
-                        (KeyCode::Left | KeyCode::Char('H' | 'h'), Some(mods))
-                            if mods.contains(KeyModifiers::SHIFT) =>
-                        {
-                            self.tuistate.issue_table.sort_previous_column();
-                            return Ok(EventPropagation::Stop);
-                        }
-                        // Show help
-                        (KeyCode::Char('?'), _) => {
-                            self.tuistate.issue_table.show_help =
-                                !self.tuistate.issue_table.show_help;
-                            return Ok(EventPropagation::Stop);
-                        }
-                        // Toggle status line
-                        (KeyCode::Char('s'), _) => {
-                            let indices = self.tuistate.issue_table.selected();
-                            if indices.is_empty() {
-                                return Ok(EventPropagation::Stop);
+                if let Some(mouse) = event.mouse() {
+                    if self.tuistate.issue_table.cell_inspect_open() {
+                        return Ok(EventPropagation::Stop);
+                    }
+                    return self.handle_table_mouse(mouse);
+                }
+                if let (Some(key), Some(mods)) = (event.keypress(), event.modifiers()) {
+                    if self.tuistate.issue_table.cell_inspect_open() {
+                        match key {
+                            KeyCode::Esc => {
+                                self.tuistate.issue_table.close_cell_inspect();
                             }
-
-                            for index in indices {
-                                let event = {
-                                    let issue = self
-                                        .issues
-                                        .get_issue(&self.tuistate.issue_table.display_map[&index])
-                                        .ok_or(IssueTablePageInputError)
-                                        .attach("unable to find issue to toggle status")?;
-                                    let issue_id = issue.id;
-                                    let status = issue.status.invert();
-                                    IssueEvent::StatusChanged { issue_id, status }
-                                };
-                                self.issues
-                                    .append_to_log(&self.args.event_log, event)
-                                    .change_context(IssueTablePageInputError)?;
+                            KeyCode::Right | KeyCode::Char('l') => {
+                                self.tuistate.issue_table.inspect_column_next();
                             }
-                            return Ok(EventPropagation::Stop);
-                        }
-                        // Cursor to next item
-                        (KeyCode::Down | KeyCode::Char('j'), _) => {
-                            self.tuistate.issue_table.cursor_next();
-                            return Ok(EventPropagation::Stop);
-                        }
-                        // Cursor to previous item
-                        (KeyCode::Up | KeyCode::Char('k'), _) => {
-                            self.tuistate.issue_table.cursor_previous();
-                            return Ok(EventPropagation::Stop);
-                        }
-                        // View issue thread
-                        (KeyCode::Enter, _) => {
-                            let indices = self.tuistate.issue_table.selected();
-                            if let Some(&index) = indices.first() {
-                                let Some(&issue_id) =
-                                    self.tuistate.issue_table.display_map.get(&index)
-                                else {
-                                    return Ok(EventPropagation::Stop);
-                                };
-                                self.tuistate.issue_thread.set_issue_id(issue_id);
-                                self.tuistate.set_page(Page::IssueThread);
-                                self.tuistate.set_focus(Focus::IssueThread);
-                                return Ok(EventPropagation::Stop);
+                            KeyCode::Left | KeyCode::Char('h') => {
+                                self.tuistate.issue_table.inspect_column_previous();
                             }
-                            return Ok(EventPropagation::Stop);
+                            _ => (),
                         }
-
-                        // Focus search filter box
-                        (KeyCode::Char('/'), _) => {
-                            if event.is_char('/') {
-                                self.tuistate.set_focus(Focus::IssueTableFilter);
-                                self.tuistate
-                                    .issue_table
-                                    .filter_input_state_mut()
-                                    .set_focused(true);
-                                return Ok(EventPropagation::Stop);
-                            }
-                        }
-                        _ => (),
+                        return Ok(EventPropagation::Stop);
+                    }
+                    if let Some(action) = self.config.keymap.resolve(Context::IssueTable, key, mods)
+                    {
+                        return self.apply(action);
                     }
                 }
             }
@@ -250,3 +149,203 @@ impl IssueTablePageInput for App {
         Ok(EventPropagation::Continue)
     }
 }
+
+impl App {
+    /// Dispatches a single [`Action`] resolved from the issue table's keymap.
+    ///
+    /// `Quit` and `Suspend` are deliberately not consumed here: they're bound in the
+    /// `IssueTable` context so they show up in a keymap dump, but `should_quit`/`should_suspend`
+    /// are only set by `App::handle_event`'s own top-level resolve, so this just lets the event
+    /// continue propagating for that to pick up.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when failing to log issue events to the event log (`ToggleStatus`,
+    /// `BumpPriority`, `CreateIssue`, `Undo`, `Redo`), or when `SyncGit`'s git history scan fails.
+    fn apply(
+        &mut self,
+        action: Action,
+    ) -> Result<EventPropagation, Report<IssueTablePageInputError>> {
+        match action {
+            Action::EditColumns => {
+                let columns = &self.tuistate.issue_table.columns;
+                let columns = IssueTableState::available_columns_for_editing(columns);
+                self.external_editor.edit(columns, "", |app, response| {
+                    if let Some(columns) = response {
+                        let columns = IssueTableState::columns_from_edited(&columns);
+                        app.tuistate.issue_table.set_columns(columns);
+                    }
+                    Ok(())
+                });
+            }
+            Action::CreateIssue => {
+                self.external_editor
+                    .edit(Issue::new_template(), "", move |app, response| {
+                        if let Some(issue) = response {
+                            let next_id = app.issues.next_issue_id();
+                            let issue = Issue::from_str(next_id, issue)
+                                .change_context(ExternalEditorError)?;
+                            if let Some((issue, comment)) = issue {
+                                app.record_event(issue)
+                                    .change_context(ExternalEditorError)?;
+                                app.record_event(comment)
+                                    .change_context(ExternalEditorError)?;
+                            }
+                        }
+                        Ok(())
+                    });
+            }
+            Action::SortDescending => {
+                self.tuistate
+                    .issue_table
+                    .set_sort_direction(SortDirection::Descending);
+            }
+            Action::SortAscending => {
+                self.tuistate
+                    .issue_table
+                    .set_sort_direction(SortDirection::Ascending);
+            }
+            Action::SortNextColumn => {
+                self.tuistate.issue_table.sort_next_column();
+            }
+            Action::SortPreviousColumn => {
+                self.tuistate.issue_table.sort_previous_column();
+            }
+            Action::ToggleHelp => {
+                self.tuistate.issue_table.show_help = !self.tuistate.issue_table.show_help;
+            }
+            // Toggle status on every marked issue (or just the cursor row if none are marked)
+            Action::ToggleStatus => {
+                for issue_id in bulk_target_ids(&self.tuistate.issue_table) {
+                    let event = {
+                        let issue = self
+                            .issues
+                            .get_issue(&issue_id)
+                            .ok_or(IssueTablePageInputError)
+                            .attach("unable to find issue to toggle status")?;
+                        let status = issue.status.cycle_next();
+                        IssueEvent::StatusChanged { issue_id, status }
+                    };
+                    self.record_event(event)
+                        .change_context(IssueTablePageInputError)?;
+                }
+            }
+            // Bump priority on every marked issue (or just the cursor row if none are marked)
+            Action::BumpPriority => {
+                for issue_id in bulk_target_ids(&self.tuistate.issue_table) {
+                    let event = {
+                        let issue = self
+                            .issues
+                            .get_issue(&issue_id)
+                            .ok_or(IssueTablePageInputError)
+                            .attach("unable to find issue to bump priority")?;
+                        let priority = issue.priority.bump();
+                        IssueEvent::PriorityChanged { issue_id, priority }
+                    };
+                    self.record_event(event)
+                        .change_context(IssueTablePageInputError)?;
+                }
+            }
+            Action::ToggleMark => {
+                if let Some(&index) = self.tuistate.issue_table.selected().first() {
+                    if let Some(&issue_id) = self.tuistate.issue_table.display_map.get(&index) {
+                        self.tuistate.issue_table.toggle_mark(issue_id);
+                    }
+                }
+            }
+            Action::InspectCell => {
+                if !self.tuistate.issue_table.selected().is_empty() {
+                    self.tuistate.issue_table.open_cell_inspect();
+                }
+            }
+            // Scan new commits for issue references/closes
+            Action::SyncGit => {
+                let since = self.issues.last_scanned_oid().map(str::to_string);
+                let events = git_link::scan(&self.args.repo, since.as_deref())
+                    .change_context(IssueTablePageInputError)
+                    .attach("failed to scan git history for issue references")?;
+                for event in events {
+                    self.record_event(event)
+                        .change_context(IssueTablePageInputError)?;
+                }
+            }
+            Action::CursorNext => self.tuistate.issue_table.cursor_next(),
+            Action::CursorPrev => self.tuistate.issue_table.cursor_previous(),
+            Action::ColumnPageNext => self.tuistate.issue_table.column_next(),
+            Action::ColumnPagePrevious => self.tuistate.issue_table.column_previous(),
+            Action::OpenThread => {
+                if let Some(&index) = self.tuistate.issue_table.selected().first() {
+                    if let Some(&issue_id) = self.tuistate.issue_table.display_map.get(&index) {
+                        self.tuistate.issue_thread.set_issue_id(issue_id);
+                        self.tuistate.set_page(Page::IssueThread);
+                        self.tuistate.set_focus(Focus::IssueThread);
+                    }
+                }
+            }
+            Action::FocusFilter => {
+                self.tuistate.set_focus(Focus::IssueTableFilter);
+                self.tuistate
+                    .issue_table
+                    .filter_input_state_mut()
+                    .set_focused(true);
+            }
+            Action::Undo => {
+                self.undo().change_context(IssueTablePageInputError)?;
+            }
+            Action::Redo => {
+                self.redo().change_context(IssueTablePageInputError)?;
+            }
+            Action::ToggleBoard => {
+                self.tuistate.set_page(Page::Board);
+                self.tuistate.set_focus(Focus::Board);
+            }
+            // Not bound to anything table-specific; `Quit`/`Suspend` are resolved by
+            // `App::handle_event`'s top-level keymap lookup, and the rest belong to other
+            // contexts (`IssueThread`).
+            Action::Quit
+            | Action::Suspend
+            | Action::Back
+            | Action::PageDown
+            | Action::PageUp
+            | Action::AddComment => return Ok(EventPropagation::Continue),
+        }
+        Ok(EventPropagation::Stop)
+    }
+
+    /// Hit-tests a mouse event against the table's geometry from the most recent render (see
+    /// `IssueTableState::set_table_geometry`). A left-click on the header cycles that column's
+    /// sort (`IssueTableState::click_header`); a left-click on a row selects it, and a second
+    /// click on the same row within the double-click window reuses `Action::OpenThread` to open
+    /// it, same as `Enter`; wheel scroll reuses `Action::CursorNext`/`CursorPrev`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as `apply`, since a double-click and wheel
+    /// scroll are both dispatched through it.
+    fn handle_table_mouse(
+        &mut self,
+        mouse: MouseEvent,
+    ) -> Result<EventPropagation, Report<IssueTablePageInputError>> {
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if self.tuistate.issue_table.is_header_row(mouse.row) {
+                    if let Some(col) = self.tuistate.issue_table.column_at(mouse.column).cloned() {
+                        self.tuistate.issue_table.click_header(col);
+                    }
+                } else if let Some(index) = self.tuistate.issue_table.row_at(mouse.row)
+                    && self.tuistate.issue_table.display_map.contains_key(&index)
+                {
+                    let is_double_click = self.tuistate.issue_table.register_click(index);
+                    self.tuistate.issue_table.cursor_to_item(index);
+                    if is_double_click {
+                        return self.apply(Action::OpenThread);
+                    }
+                }
+            }
+            MouseEventKind::ScrollDown => return self.apply(Action::CursorNext),
+            MouseEventKind::ScrollUp => return self.apply(Action::CursorPrev),
+            _ => return Ok(EventPropagation::Continue),
+        }
+        Ok(EventPropagation::Stop)
+    }
+}
@@ -1,18 +1,27 @@
 mod draw;
+mod filter;
+mod fuzzy;
 mod input;
 mod state;
 
+use std::{borrow::Cow, cmp::Ordering};
+
 pub use draw::IssueTableDraw;
 use error_stack::Report;
+pub use filter::{Comparator, Field, FieldTerm, Filter};
+use filter::{
+    apply_issue_filter, best_match_score, query_has_error, title_match_ranges, title_query_text,
+};
 pub use input::IssueTablePageInput;
+use jiff::Timestamp;
+use serde::{Deserialize, Deserializer};
 pub use state::IssueTableState;
 use strum::EnumIter;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 use wherror::Error;
 
-use crate::feat::{
-    issue::{Issue, Status},
-    issues::Issues,
-};
+use crate::feat::issue::Issue;
 
 #[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum SortDirection {
@@ -21,6 +30,18 @@ pub enum SortDirection {
     Descending,
 }
 
+/// Where an issue lacking a `Column::Custom` field should land when sorting on it.
+///
+/// Used by [`apply_issue_sort`] and configurable via `AppConfig::custom_sort_missing`. The
+/// chosen extreme is pinned regardless of `SortDirection`, so switching sort direction never
+/// shuffles issues that simply don't have the field.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, Deserialize)]
+pub enum MissingValueOrder {
+    First,
+    #[default]
+    Last,
+}
+
 #[derive(Debug, Error)]
 #[error(debug)]
 pub struct ColumnParseError;
@@ -50,6 +71,19 @@ impl std::fmt::Display for Column {
     }
 }
 
+/// Deserializes the same names [`Column::FromStr`] accepts, so `AppConfig::column_display`'s
+/// keys can be written as plain strings (e.g. `"Priority"`) in a RON config file. Never fails:
+/// an unrecognized name becomes a `Custom` column, same as `FromStr`.
+impl<'de> Deserialize<'de> for Column {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(s.parse().expect("Column::from_str is infallible"))
+    }
+}
+
 impl std::str::FromStr for Column {
     type Err = Report<ColumnParseError>;
 
@@ -67,22 +101,316 @@ impl std::str::FromStr for Column {
     }
 }
 
-fn apply_issue_filter<'a>(filter_text: &str, issues: &'a Issues) -> Vec<&'a Issue> {
-    let filter_text = filter_text.to_lowercase();
-    let remove_closed = filter_text.contains("!closed");
-    let remove_open = filter_text.contains("!open");
-    let filter_text = filter_text.replace("!closed", "");
-    let filter_text = filter_text.replace("!open", "");
-    issues
-        .iter_issues()
-        .filter(|issue| !(remove_open && issue.status == Status::Open))
-        .filter(|issue| !(remove_closed && issue.status == Status::Closed))
-        .filter(|issue| filter_text.is_empty() || issue.title.to_lowercase().contains(&filter_text))
-        .collect()
+impl Column {
+    /// Whether this column is pinned as a frozen leftmost column by [`IssueTableDraw::render`],
+    /// exempt from horizontal column paging.
+    fn is_frozen(&self) -> bool {
+        matches!(self, Column::Id | Column::Title)
+    }
+
+    /// A fixed width estimate used only for horizontal-paging math (deciding how many columns
+    /// fit on screen); the actual rendered width still comes from the `Constraint` built
+    /// alongside it in `IssueTableDraw::render`, so e.g. `Title`'s real width stays `Fill(1)`.
+    fn paging_width(&self) -> u16 {
+        match self {
+            Column::Id => 4,
+            Column::Title => 20,
+            Column::Created => 20,
+            Column::Status => 8,
+            Column::Priority => 11,
+            Column::CreatedBy => 30,
+            Column::Custom(_) => 12,
+        }
+    }
+
+    /// This column's value for `issue`, as rendered in a table cell (and, unwrapped, in the
+    /// [`IssueTableDraw::render`] cell-inspection popup).
+    pub(in crate::feat::tui_issue_table) fn cell_text<'a>(&self, issue: &'a Issue) -> Cow<'a, str> {
+        match self {
+            Column::Id => issue.id.to_string().into(),
+            Column::Title => issue.title.as_str().into(),
+            Column::Created => issue.created.strftime("%FT%TZ").to_string().into(),
+            Column::Status => issue.status.to_string().into(),
+            Column::Priority => format!("{:?}", issue.priority).into(),
+            Column::CreatedBy => issue.created_by.as_str().into(),
+            Column::Custom(key) => issue
+                .custom
+                .get(key.as_str())
+                .map_or(String::default().into(), Cow::from),
+        }
+    }
+
+    /// This column's default rendering config, used unless `IssueTableState::column_display` has
+    /// an override for it. Mirrors the widths [`IssueTableDraw::render`] used to hardcode, plus a
+    /// sensible default alignment (`Id` right-aligned, everything else left-aligned).
+    pub(in crate::feat::tui_issue_table) fn default_display(&self) -> ColumnDisplay {
+        match self {
+            Column::Id => ColumnDisplay {
+                align: ColumnAlign::Right,
+                min_width: 4,
+                max_width: Some(4),
+            },
+            Column::Title => ColumnDisplay {
+                align: ColumnAlign::Left,
+                min_width: 10,
+                max_width: None,
+            },
+            Column::Created => ColumnDisplay {
+                align: ColumnAlign::Left,
+                min_width: 20,
+                max_width: Some(20),
+            },
+            Column::Status => ColumnDisplay {
+                align: ColumnAlign::Left,
+                min_width: 8,
+                max_width: Some(8),
+            },
+            Column::Priority => ColumnDisplay {
+                align: ColumnAlign::Left,
+                min_width: 11,
+                max_width: Some(11),
+            },
+            Column::CreatedBy => ColumnDisplay {
+                align: ColumnAlign::Left,
+                min_width: 30,
+                max_width: Some(30),
+            },
+            Column::Custom(_) => ColumnDisplay {
+                align: ColumnAlign::Left,
+                min_width: 12,
+                max_width: None,
+            },
+        }
+    }
+}
+
+/// Horizontal alignment for a table column's cell content; see [`ColumnDisplay`].
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, Deserialize)]
+pub enum ColumnAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+impl From<ColumnAlign> for ratatui::layout::Alignment {
+    fn from(align: ColumnAlign) -> Self {
+        match align {
+            ColumnAlign::Left => ratatui::layout::Alignment::Left,
+            ColumnAlign::Center => ratatui::layout::Alignment::Center,
+            ColumnAlign::Right => ratatui::layout::Alignment::Right,
+        }
+    }
+}
+
+/// Per-column rendering knobs carried on `IssueTableState::column_display`: alignment, a minimum
+/// width the column's `Constraint` is never narrower than, and an optional max width beyond which
+/// cell text is truncated with a trailing `…`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct ColumnDisplay {
+    pub align: ColumnAlign,
+    pub min_width: u16,
+    pub max_width: Option<u16>,
+}
+
+/// Truncates `text` to fit within `max_width` display columns, appending `…` when truncation was
+/// needed. Width is measured in display columns, grapheme by grapheme (not bytes or `chars`), so
+/// multibyte titles don't silently overflow their column. Returns `text` unchanged when it
+/// already fits or `max_width` is `None`.
+pub(in crate::feat::tui_issue_table) fn truncate_display(
+    text: &str,
+    max_width: Option<u16>,
+) -> Cow<'_, str> {
+    let Some(max_width) = max_width else {
+        return Cow::Borrowed(text);
+    };
+    let max_width = usize::from(max_width);
+    if text.width() <= max_width {
+        return Cow::Borrowed(text);
+    }
+    if max_width == 0 {
+        return Cow::Borrowed("");
+    }
+
+    let budget = max_width - 1;
+    let mut out = String::new();
+    let mut width = 0;
+    for grapheme in text.graphemes(true) {
+        let grapheme_width = grapheme.width();
+        if width + grapheme_width > budget {
+            break;
+        }
+        width += grapheme_width;
+        out.push_str(grapheme);
+    }
+    out.push('…');
+    Cow::Owned(out)
+}
+
+/// How the present (non-missing) values of a `Column::Custom` field compare to one another.
+///
+/// Inferred once per sort from every present value in the filtered set, not per-row, so that a
+/// field is either numeric, chronological, or string-ordered for the whole column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CustomColumnKind {
+    Numeric,
+    Chronological,
+    Natural,
+}
+
+/// Infers how the custom field `key` should compare, from the values present in `issues`.
+///
+/// Numeric wins if every present value parses as `f64`; chronological if every present value
+/// parses as an RFC3339 timestamp; otherwise falls back to natural string ordering. A column with
+/// no present values at all is harmless to call this on and just defaults to `Natural`.
+fn infer_custom_column_kind(issues: &[&Issue], key: &str) -> CustomColumnKind {
+    let present: Vec<&str> = issues
+        .iter()
+        .filter_map(|issue| issue.custom.get(key))
+        .map(String::as_str)
+        .collect();
+
+    if !present.is_empty() && present.iter().all(|v| v.parse::<f64>().is_ok()) {
+        CustomColumnKind::Numeric
+    } else if !present.is_empty() && present.iter().all(|v| v.parse::<Timestamp>().is_ok()) {
+        CustomColumnKind::Chronological
+    } else {
+        CustomColumnKind::Natural
+    }
+}
+
+/// Case-insensitive natural-order comparison, so `"item2"` sorts before `"item10"`.
+///
+/// Splits both strings into runs of digits and non-digits, compares digit runs numerically and
+/// non-digit runs case-insensitively, and falls through to the next run pair on a tie.
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    fn split_runs(s: &str) -> Vec<&str> {
+        let mut runs = Vec::new();
+        let bytes = s.as_bytes();
+        let mut start = 0;
+        while start < bytes.len() {
+            let is_digit = bytes[start].is_ascii_digit();
+            let mut end = start + 1;
+            while end < bytes.len() && bytes[end].is_ascii_digit() == is_digit {
+                end += 1;
+            }
+            runs.push(&s[start..end]);
+            start = end;
+        }
+        runs
+    }
+
+    let runs_a = split_runs(a);
+    let runs_b = split_runs(b);
+
+    for pair in runs_a.iter().zip(runs_b.iter()) {
+        let (run_a, run_b) = pair;
+        let ord = match (run_a.parse::<u128>(), run_b.parse::<u128>()) {
+            (Ok(num_a), Ok(num_b)) => num_a.cmp(&num_b),
+            _ => run_a.to_lowercase().cmp(&run_b.to_lowercase()),
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    runs_a.len().cmp(&runs_b.len())
+}
+
+/// Compares two issues' values for the custom field `key`, pinning missing values to
+/// `missing_order` regardless of `sort_dir`.
+///
+/// The returned ordering is pre-adjusted for `sort_dir`, so callers must apply it as-is (not
+/// reverse it again) when `sort_col` is `Column::Custom`.
+fn compare_custom_values(
+    issue1: &Issue,
+    issue2: &Issue,
+    key: &str,
+    kind: CustomColumnKind,
+    missing_order: MissingValueOrder,
+    sort_dir: SortDirection,
+) -> Ordering {
+    let value1 = issue1.custom.get(key).map(String::as_str);
+    let value2 = issue2.custom.get(key).map(String::as_str);
+
+    let ord = match (value1, value2) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => match missing_order {
+            MissingValueOrder::First => Ordering::Less,
+            MissingValueOrder::Last => Ordering::Greater,
+        },
+        (Some(_), None) => match missing_order {
+            MissingValueOrder::First => Ordering::Greater,
+            MissingValueOrder::Last => Ordering::Less,
+        },
+        (Some(v1), Some(v2)) => match kind {
+            CustomColumnKind::Numeric => v1
+                .parse::<f64>()
+                .ok()
+                .zip(v2.parse::<f64>().ok())
+                .and_then(|(n1, n2)| n1.partial_cmp(&n2))
+                .unwrap_or_else(|| natural_cmp(v1, v2)),
+            CustomColumnKind::Chronological => v1
+                .parse::<Timestamp>()
+                .ok()
+                .zip(v2.parse::<Timestamp>().ok())
+                .map_or_else(|| natural_cmp(v1, v2), |(t1, t2)| t1.cmp(&t2)),
+            CustomColumnKind::Natural => natural_cmp(v1, v2),
+        },
+    };
+
+    let is_missing_pair = value1.is_none() || value2.is_none();
+    if is_missing_pair {
+        // `missing_order` is an absolute placement, so it isn't reversed for `sort_dir`.
+        ord
+    } else {
+        match sort_dir {
+            SortDirection::Ascending => ord,
+            SortDirection::Descending => ord.reverse(),
+        }
+    }
 }
 
-fn apply_issue_sort(filtered_issues: &mut Vec<&Issue>, sort_col: &Column, sort_dir: SortDirection) {
+/// Sorts `filtered_issues` for display.
+///
+/// When `filter_text` carries plain (non field-scoped) query words, issues are primarily ranked
+/// by descending fuzzy match score against that query (see `fuzzy::fuzzy_score`), with `sort_col`
+/// only breaking ties between equally-ranked issues. Otherwise `sort_col`/`sort_dir` drive the
+/// order as before.
+///
+/// Sorting on `Column::Custom(key)` pulls `key` out of each issue's `custom` map: if every
+/// present value parses as a number the column sorts numerically, if every present value parses
+/// as an RFC3339 timestamp it sorts chronologically, and otherwise it falls back to
+/// case-insensitive natural string ordering. Issues missing `key` are pinned to `missing_order`
+/// regardless of `sort_dir`.
+fn apply_issue_sort(
+    filtered_issues: &mut Vec<&Issue>,
+    sort_col: &Column,
+    sort_dir: SortDirection,
+    filter_text: &str,
+    missing_order: MissingValueOrder,
+) {
+    let ranking_query = title_query_text(filter_text);
+    let custom_kind = match sort_col {
+        Column::Custom(key) => Some(infer_custom_column_kind(filtered_issues, key)),
+        _ => None,
+    };
+
     filtered_issues.sort_by(|issue1, issue2| {
+        if let Some(query) = &ranking_query {
+            let score1 = best_match_score(query, issue1);
+            let score2 = best_match_score(query, issue2);
+            if let (Some(score1), Some(score2)) = (score1, score2) {
+                match score2.cmp(&score1) {
+                    Ordering::Equal => (),
+                    rank_order => return rank_order,
+                }
+            }
+        }
+
+        if let (Column::Custom(key), Some(kind)) = (sort_col, custom_kind) {
+            return compare_custom_values(issue1, issue2, key, kind, missing_order, sort_dir);
+        }
+
         let ord = match sort_col {
             Column::Id => issue1.id.cmp(&issue2.id),
             Column::Title => issue1.title.cmp(&issue2.title),
@@ -90,7 +418,7 @@ fn apply_issue_sort(filtered_issues: &mut Vec<&Issue>, sort_col: &Column, sort_d
             Column::Status => issue1.status.cmp(&issue2.status),
             Column::Priority => issue1.priority.cmp(&issue2.priority),
             Column::CreatedBy => issue1.created_by.cmp(&issue2.created_by),
-            Column::Custom(_) => todo!(),
+            Column::Custom(_) => unreachable!("handled above"),
         };
         match sort_dir {
             SortDirection::Ascending => ord,
@@ -101,9 +429,13 @@ fn apply_issue_sort(filtered_issues: &mut Vec<&Issue>, sort_col: &Column, sort_d
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use std::collections::HashMap;
+
     use rstest::rstest;
 
+    use super::*;
+    use crate::feat::issue::{IssueId, Priority, Status};
+
     #[rstest]
     #[case("id", Column::Id)]
     #[case("ID", Column::Id)]
@@ -147,4 +479,96 @@ mod tests {
             _ => panic!("Expected `Column::Custom({expected:?})`, got {col:?}"),
         }
     }
+
+    fn issue(id: IssueId, custom: Option<(&str, &str)>) -> Issue {
+        Issue {
+            id,
+            title: format!("issue {id}"),
+            created: Timestamp::now(),
+            status: Status::open(),
+            priority: Priority::Low,
+            created_by: "alice".to_string(),
+            custom: custom
+                .map(|(k, v)| HashMap::from([(k.to_string(), v.to_string())]))
+                .unwrap_or_default(),
+        }
+    }
+
+    fn sort_values(
+        values: &[Option<&str>],
+        missing_order: MissingValueOrder,
+        sort_dir: SortDirection,
+    ) -> Vec<Option<String>> {
+        let issues: Vec<Issue> = values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| issue(i as IssueId, v.map(|v| ("field", v))))
+            .collect();
+        let mut refs: Vec<&Issue> = issues.iter().collect();
+        apply_issue_sort(
+            &mut refs,
+            &Column::Custom("field".to_string()),
+            sort_dir,
+            "",
+            missing_order,
+        );
+        refs.iter()
+            .map(|issue| issue.custom.get("field").cloned())
+            .collect()
+    }
+
+    #[rstest]
+    #[case(&["10", "2", "1"], &["1", "2", "10"])]
+    #[case(&["-3", "0", "5"], &["-3", "0", "5"])]
+    fn test_custom_sort_numeric(#[case] input: &[&str], #[case] expected: &[&str]) {
+        let values: Vec<Option<&str>> = input.iter().map(|v| Some(*v)).collect();
+        let sorted = sort_values(&values, MissingValueOrder::Last, SortDirection::Ascending);
+        let sorted: Vec<&str> = sorted.iter().map(|v| v.as_deref().unwrap()).collect();
+        assert_eq!(sorted, expected);
+    }
+
+    #[rstest]
+    #[case(&["item2", "item10", "item1"], &["item1", "item2", "item10"])]
+    #[case(&["Banana", "apple"], &["apple", "Banana"])]
+    fn test_custom_sort_natural_string(#[case] input: &[&str], #[case] expected: &[&str]) {
+        let values: Vec<Option<&str>> = input.iter().map(|v| Some(*v)).collect();
+        let sorted = sort_values(&values, MissingValueOrder::Last, SortDirection::Ascending);
+        let sorted: Vec<&str> = sorted.iter().map(|v| v.as_deref().unwrap()).collect();
+        assert_eq!(sorted, expected);
+    }
+
+    #[rstest]
+    fn test_custom_sort_chronological() {
+        let values = [
+            Some("2024-03-01T00:00:00Z"),
+            Some("2023-01-01T00:00:00Z"),
+            Some("2025-06-15T00:00:00Z"),
+        ];
+        let sorted = sort_values(&values, MissingValueOrder::Last, SortDirection::Ascending);
+        let sorted: Vec<&str> = sorted.iter().map(|v| v.as_deref().unwrap()).collect();
+        assert_eq!(
+            sorted,
+            &[
+                "2023-01-01T00:00:00Z",
+                "2024-03-01T00:00:00Z",
+                "2025-06-15T00:00:00Z"
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(MissingValueOrder::Last, SortDirection::Ascending, &[Some("1"), Some("2"), None])]
+    #[case(MissingValueOrder::Last, SortDirection::Descending, &[Some("2"), Some("1"), None])]
+    #[case(MissingValueOrder::First, SortDirection::Ascending, &[None, Some("1"), Some("2")])]
+    #[case(MissingValueOrder::First, SortDirection::Descending, &[None, Some("2"), Some("1")])]
+    fn test_custom_sort_missing_is_pinned(
+        #[case] missing_order: MissingValueOrder,
+        #[case] sort_dir: SortDirection,
+        #[case] expected: &[Option<&str>],
+    ) {
+        let values = [Some("2"), None, Some("1")];
+        let sorted = sort_values(&values, missing_order, sort_dir);
+        let sorted: Vec<Option<&str>> = sorted.iter().map(|v| v.as_deref()).collect();
+        assert_eq!(sorted, expected);
+    }
 }
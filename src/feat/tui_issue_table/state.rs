@@ -1,11 +1,14 @@
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    time::{Duration, Instant},
+};
 
-use ratatui::widgets::TableState;
+use ratatui::{layout::Rect, widgets::TableState};
 use strum::IntoEnumIterator;
 
 use crate::feat::{
     issue::IssueId,
-    tui_issue_table::{Column, SortDirection},
+    tui_issue_table::{Column, ColumnDisplay, SortDirection},
     tui_widget::InputBoxState,
 };
 
@@ -18,7 +21,44 @@ pub struct IssueTableState {
     pub(in crate::feat::tui_issue_table) columns: Vec<Column>,
     pub(in crate::feat::tui_issue_table) show_help: bool,
 
+    /// Per-column alignment/width overrides, keyed by `Column`; a column with no entry here
+    /// falls back to `Column::default_display`. See [`Self::column_display`].
+    pub(in crate::feat::tui_issue_table) column_display: HashMap<Column, ColumnDisplay>,
+
     pub(in crate::feat::tui_issue_table) display_map: HashMap<usize, IssueId>,
+
+    /// Index (among the non-frozen columns) of the first one rendered; see
+    /// [`IssueTableState::column_next`].
+    pub(in crate::feat::tui_issue_table) column_page: usize,
+    /// Index (among the non-frozen columns) of the column the cursor is on.
+    pub(in crate::feat::tui_issue_table) column_index: usize,
+    /// How many non-frozen columns fit on screen as of the last render; updated by
+    /// `IssueTableDraw::render` and consulted by [`IssueTableState::column_next`] to decide when
+    /// the cursor has scrolled off the visible window.
+    pub(in crate::feat::tui_issue_table) visible_column_count: usize,
+
+    /// Whether the cell-inspection popup (see [`Self::open_cell_inspect`]) is open.
+    pub(in crate::feat::tui_issue_table) cell_inspect: bool,
+    /// Index into the full, unpaged `columns` list of the cell currently shown by the
+    /// inspection popup. Unlike `column_index` (which only ranges over non-frozen columns, for
+    /// paging purposes), this ranges over every configured column so frozen columns like `Title`
+    /// can be inspected too.
+    pub(in crate::feat::tui_issue_table) inspect_column: usize,
+
+    /// Issues marked for the bulk `s`/`p` actions; resolved by `IssueId` (rather than table row
+    /// index) so a mark survives re-filtering or re-sorting the list.
+    pub(in crate::feat::tui_issue_table) marked: HashSet<IssueId>,
+
+    /// The table's on-screen area (header row plus body) as of the most recent render, and the
+    /// x-bounds of each rendered column within it. Recorded by `IssueTableDraw::render` so mouse
+    /// events (which only carry screen coordinates) can be hit-tested against it; see
+    /// [`Self::row_at`]/[`Self::column_at`].
+    pub(in crate::feat::tui_issue_table) table_area: Rect,
+    pub(in crate::feat::tui_issue_table) column_bounds: Vec<(Column, u16, u16)>,
+
+    /// The time and row index of the last left-click, for double-click detection; see
+    /// [`Self::register_click`].
+    pub(in crate::feat::tui_issue_table) last_click: Option<(Instant, usize)>,
 }
 
 impl Default for IssueTableState {
@@ -28,6 +68,7 @@ impl Default for IssueTableState {
             filter_input: {
                 let mut input = InputBoxState::default();
                 input.set_text("!closed ");
+                input.set_multiline(false);
                 input
             },
             sort_by: Column::Created,
@@ -41,7 +82,17 @@ impl Default for IssueTableState {
                 Column::CreatedBy,
             ],
             show_help: false,
+            column_display: HashMap::default(),
             display_map: HashMap::default(),
+            column_page: 0,
+            column_index: 0,
+            visible_column_count: 1,
+            cell_inspect: false,
+            inspect_column: 0,
+            marked: HashSet::default(),
+            table_area: Rect::default(),
+            column_bounds: Vec::new(),
+            last_click: None,
         }
     }
 }
@@ -61,6 +112,24 @@ impl IssueTableState {
         }
     }
 
+    /// Whether `id` is marked for a bulk action.
+    pub fn is_marked(&self, id: IssueId) -> bool {
+        self.marked.contains(&id)
+    }
+
+    /// Toggles whether `id` is marked for the bulk `s`/`p` actions.
+    pub fn toggle_mark(&mut self, id: IssueId) {
+        if !self.marked.remove(&id) {
+            self.marked.insert(id);
+        }
+    }
+
+    /// The currently marked issues. Callers of a bulk action should fall back to just the cursor
+    /// row when this is empty.
+    pub fn marked(&self) -> &HashSet<IssueId> {
+        &self.marked
+    }
+
     pub fn sort_next_column(&mut self) {
         if let Some(i) = self.columns.iter().position(|c| *c == self.sort_by) {
             let next_i = (i + 1) % self.columns.len();
@@ -75,6 +144,91 @@ impl IssueTableState {
         }
     }
 
+    /// The configured columns that aren't pinned as a frozen leftmost column, i.e. the ones
+    /// horizontal paging scrolls through.
+    fn scrollable_column_count(&self) -> usize {
+        self.columns.iter().filter(|c| !c.is_frozen()).count()
+    }
+
+    /// Records how many non-frozen columns fit on screen in the most recent render, so
+    /// [`Self::column_next`]/[`Self::column_previous`] know when the cursor has scrolled off the
+    /// visible window.
+    pub fn set_visible_column_count(&mut self, count: usize) {
+        self.visible_column_count = count.max(1);
+    }
+
+    pub fn column_page(&self) -> usize {
+        self.column_page
+    }
+
+    /// Moves the column cursor right by one, advancing `column_page` if that scrolls the cursor
+    /// past the last column currently on screen.
+    pub fn column_next(&mut self) {
+        let len = self.scrollable_column_count();
+        if len == 0 {
+            return;
+        }
+        self.column_index = (self.column_index + 1).min(len - 1);
+        if self.column_index >= self.column_page + self.visible_column_count {
+            self.column_page = self.column_index + 1 - self.visible_column_count;
+        }
+    }
+
+    /// Moves the column cursor left by one, retreating `column_page` if that scrolls the cursor
+    /// before the first column currently on screen.
+    pub fn column_previous(&mut self) {
+        self.column_index = self.column_index.saturating_sub(1);
+        if self.column_index < self.column_page {
+            self.column_page = self.column_index;
+        }
+    }
+
+    /// Whether the cell-inspection popup is currently open.
+    pub fn cell_inspect_open(&self) -> bool {
+        self.cell_inspect
+    }
+
+    /// Opens the cell-inspection popup for the currently focused column.
+    pub fn open_cell_inspect(&mut self) {
+        self.cell_inspect = true;
+        if let Some(index) = self
+            .columns
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| !c.is_frozen())
+            .nth(self.column_index)
+            .map(|(index, _)| index)
+        {
+            self.inspect_column = index;
+        }
+    }
+
+    /// Dismisses the cell-inspection popup.
+    pub fn close_cell_inspect(&mut self) {
+        self.cell_inspect = false;
+    }
+
+    /// The index into [`Self::columns`] of the cell currently shown by the inspection popup.
+    pub fn inspect_column(&self) -> usize {
+        self.inspect_column
+    }
+
+    /// Moves the inspected cell to the next column, wrapping to the first after the last.
+    pub fn inspect_column_next(&mut self) {
+        if self.columns.is_empty() {
+            return;
+        }
+        self.inspect_column = (self.inspect_column + 1) % self.columns.len();
+    }
+
+    /// Moves the inspected cell to the previous column, wrapping to the last after the first.
+    pub fn inspect_column_previous(&mut self) {
+        if self.columns.is_empty() {
+            return;
+        }
+        self.inspect_column = (self.inspect_column + self.columns.len() - 1) % self.columns.len();
+    }
+
     pub fn cursor_previous(&mut self) {
         if let Some(current) = self.table.selected() {
             let index = current.saturating_sub(1);
@@ -111,12 +265,30 @@ impl IssueTableState {
 
     pub fn set_columns(&mut self, columns: Vec<Column>) {
         self.columns = columns;
+        self.column_page = 0;
+        self.column_index = 0;
+        self.inspect_column = 0;
     }
 
     pub fn columns_mut(&mut self) -> &mut Vec<Column> {
         &mut self.columns
     }
 
+    /// This column's rendering config: alignment, min width, and truncation max width. Falls
+    /// back to `Column::default_display` when `col` has no override set via
+    /// [`Self::set_column_display`].
+    pub fn column_display(&self, col: &Column) -> ColumnDisplay {
+        self.column_display
+            .get(col)
+            .copied()
+            .unwrap_or_else(|| col.default_display())
+    }
+
+    /// Overrides the alignment/width config used to render `col`.
+    pub fn set_column_display(&mut self, col: Column, display: ColumnDisplay) {
+        self.column_display.insert(col, display);
+    }
+
     pub fn sort_direction(&self) -> &SortDirection {
         &self.sort_direction
     }
@@ -156,6 +328,61 @@ impl IssueTableState {
         lines.join("\n")
     }
 
+    /// Records the table's on-screen geometry for the current render; see [`Self::table_area`].
+    pub fn set_table_geometry(&mut self, area: Rect, column_bounds: Vec<(Column, u16, u16)>) {
+        self.table_area = area;
+        self.column_bounds = column_bounds;
+    }
+
+    /// Whether screen row `y` is the table's header row.
+    pub fn is_header_row(&self, y: u16) -> bool {
+        self.table_area.height > 0 && y == self.table_area.y
+    }
+
+    /// The selected-row index for screen row `y` inside the table body (below the header),
+    /// accounting for the current scroll offset. `None` if `y` is on the header or outside the
+    /// table entirely.
+    pub fn row_at(&self, y: u16) -> Option<usize> {
+        let body_top = self.table_area.y.checked_add(1)?;
+        if y < body_top || y >= self.table_area.y.saturating_add(self.table_area.height) {
+            return None;
+        }
+        Some(self.table.offset() + usize::from(y - body_top))
+    }
+
+    /// The column whose rendered bounds contain screen column `x`, if any.
+    pub fn column_at(&self, x: u16) -> Option<&Column> {
+        self.column_bounds
+            .iter()
+            .find(|(_, start, end)| (*start..*end).contains(&x))
+            .map(|(col, _, _)| col)
+    }
+
+    /// Registers a left-click on row `index`, returning `true` if it's a double-click (another
+    /// click on the same row within 400ms).
+    pub fn register_click(&mut self, index: usize) -> bool {
+        let now = Instant::now();
+        let is_double_click = self.last_click.is_some_and(|(at, prev)| {
+            prev == index && now.duration_since(at) < Duration::from_millis(400)
+        });
+        self.last_click = Some((now, index));
+        is_double_click
+    }
+
+    /// Handles a click on a column header: toggles sort direction if `col` is already the sort
+    /// column (same as clicking an already-sorted column header in most spreadsheet UIs),
+    /// otherwise switches to sorting by `col` without changing direction.
+    pub fn click_header(&mut self, col: Column) {
+        if col == self.sort_by {
+            self.sort_direction = match self.sort_direction {
+                SortDirection::Ascending => SortDirection::Descending,
+                SortDirection::Descending => SortDirection::Ascending,
+            };
+        } else {
+            self.sort_by = col;
+        }
+    }
+
     pub fn columns_from_edited(input: &str) -> Vec<Column> {
         let input = input.trim();
         input
@@ -177,6 +404,37 @@ mod tests {
     use super::*;
     use rstest::rstest;
 
+    use crate::feat::{
+        issue::{Issue, Priority, Status},
+        tui_issue_table::filter::{eval, parse_filter},
+    };
+
+    /// Regression test for the launch-time bug where `IssueTableState::default`'s seed filter
+    /// text (`!closed `) parsed under the new query grammar (chunk1-1) as a literal title search
+    /// for the characters `!closed`, matching virtually no real issue and leaving every fresh
+    /// launch looking empty. Fixed by chunk3-6's legacy `open`/`closed` status-token mapping.
+    #[test]
+    fn default_seed_filter_hides_closed_and_keeps_open() {
+        let state = IssueTableState::default();
+        let text = state.filter_input_state().text();
+        let filter = parse_filter(&text).expect("default seed text should parse to a filter");
+
+        let open = Issue {
+            id: 1,
+            title: "Ordinary issue title".to_string(),
+            created: jiff::Timestamp::now(),
+            status: Status::open(),
+            priority: Priority::Low,
+            created_by: "alice".to_string(),
+            custom: std::collections::HashMap::new(),
+        };
+        let mut closed = open.clone();
+        closed.status = Status::closed();
+
+        assert!(eval(&filter, &open));
+        assert!(!eval(&filter, &closed));
+    }
+
     #[rstest]
     #[case(vec![], "# ID\n# Title\n# Created\n# Status\n# Priority\n# Created By")]
     #[case(vec![Column::Id], "ID\n# Title\n# Created\n# Status\n# Priority\n# Created By")]
@@ -6,19 +6,38 @@ use tui_widget_list::{ListBuilder, ListView};
 
 use crate::{
     App,
-    feat::{issue::Comment, tui_widget::HelpPopup},
+    feat::{
+        issue::Comment,
+        markdown,
+        tui_widget::{HelpPopup, InputBox},
+    },
 };
 
 // Define LineItem before impl IssueThreadDraw:
 #[derive(Debug, Clone)]
 struct LineItem {
-    text: String,
-    style: Style,
+    line: Line<'static>,
+    selected: bool,
 }
 
 impl Widget for LineItem {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        Line::from(self.text).style(self.style).render(area, buf);
+        let line = if self.selected {
+            let selection_style = Style::default()
+                .bg(Color::DarkGray)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD);
+            let spans = self
+                .line
+                .spans
+                .into_iter()
+                .map(|span| Span::styled(span.content, span.style.patch(selection_style)))
+                .collect::<Vec<_>>();
+            Line::from(spans)
+        } else {
+            self.line
+        };
+        line.render(area, buf);
     }
 }
 
@@ -45,6 +64,7 @@ impl IssueThreadDraw for &mut App {
         block.render(area, buf);
 
         let list_state = &mut self.tuistate.issue_thread.list_state;
+        let markdown_cache = &mut self.tuistate.issue_thread.markdown_cache;
 
         let comments: Vec<&Comment> = self
             .issues
@@ -54,15 +74,19 @@ impl IssueThreadDraw for &mut App {
             .next()
             .unwrap_or_default();
 
-        let line_data: Vec<String> = {
+        let line_data: Vec<Line<'static>> = {
             let mut data = vec![
-                format!("Status: {:?}", issue.status),
-                format!("Priority: {}", issue.priority),
-                format!("Created: {}", issue.created.strftime("%Y-%m-%d %H:%M:%S")),
-                format!("Created by: {}", issue.created_by),
-                String::new(),
+                Line::from(format!("Status: {}", issue.status)),
+                Line::from(format!("Priority: {}", issue.priority)),
+                Line::from(format!(
+                    "Created: {}",
+                    issue.created.strftime("%Y-%m-%d %H:%M:%S")
+                )),
+                Line::from(format!("Created by: {}", issue.created_by)),
+                Line::default(),
             ];
             let indent_width = 3;
+            let indent = " ".repeat(indent_width);
             let max_width = inner_area.width.saturating_sub(indent_width as u16).max(1) as usize;
             for comment in &comments {
                 let header = format!(
@@ -70,19 +94,19 @@ impl IssueThreadDraw for &mut App {
                     comment.created_by,
                     comment.created.strftime("%Y-%m-%d %H:%M:%S")
                 );
-                data.push(header);
-                for line in comment.content.lines() {
-                    for wrapped in textwrap::wrap(line, max_width) {
-                        let trimmed_line = wrapped.trim_start();
-                        if !trimmed_line.is_empty() {
-                            data.push(format!("{:indent_width$} {}", "", trimmed_line));
-                        }
-                    }
-                    if line.trim().is_empty() {
-                        data.push(format!("{:indent_width$}", ""));
+                data.push(Line::from(header));
+
+                let rendered = markdown_cache.get_or_render(&comment.content);
+                for wrapped in markdown::wrap_text(&rendered, max_width) {
+                    if wrapped.spans.is_empty() {
+                        data.push(Line::default());
+                        continue;
                     }
+                    let mut spans = vec![Span::raw(indent.clone())];
+                    spans.extend(wrapped.spans);
+                    data.push(Line::from(spans));
                 }
-                data.push(String::new());
+                data.push(Line::default());
             }
             data
         };
@@ -102,17 +126,11 @@ impl IssueThreadDraw for &mut App {
         }
 
         let builder = ListBuilder::new(move |context| {
-            let text = line_data[context.index].clone();
-            let mut item = LineItem {
-                text,
-                style: Style::default(),
+            let line = line_data[context.index].clone();
+            let item = LineItem {
+                line,
+                selected: context.is_selected,
             };
-            if context.is_selected {
-                item.style = Style::default()
-                    .bg(Color::DarkGray)
-                    .fg(Color::White)
-                    .add_modifier(Modifier::BOLD);
-            }
             (item, 1u16)
         });
 
@@ -120,6 +138,35 @@ impl IssueThreadDraw for &mut App {
 
         list.render(inner_area, buf, list_state);
 
+        if self.tuistate.issue_thread.composing() {
+            let area = *buf.area();
+            let popup_width = (area.width * 3 / 4).clamp(20, area.width);
+            let popup_height = (area.height * 2 / 3).clamp(5, area.height);
+            let popup_rect = Rect {
+                x: area.x + (area.width.saturating_sub(popup_width)) / 2,
+                y: area.y + (area.height.saturating_sub(popup_height)) / 2,
+                width: popup_width,
+                height: popup_height,
+            };
+
+            buf.set_style(popup_rect, Style::default().bg(Color::Rgb(30, 30, 30)));
+
+            let block = Block::default()
+                .title(" Add comment (Ctrl-Enter submit, Esc cancel) ")
+                .title_alignment(Alignment::Center)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray));
+            let inner_area = block.inner(popup_rect);
+            block.render(popup_rect, buf);
+
+            StatefulWidget::render(
+                InputBox::new(),
+                inner_area,
+                buf,
+                self.tuistate.issue_thread.comment_input_state_mut(),
+            );
+        }
+
         if self.tuistate.issue_thread.show_help {
             let items = vec![
                 ("q, <esc>", "Back to issues"),
@@ -5,17 +5,16 @@ use wherror::Error;
 use crate::{
     App,
     feat::{
-        external_editor::ExternalEditorError,
         issue::Comment,
+        keymap::{Action, Context},
         tui::{Event, EventExt, EventPropagation, Focus, KeyCode, KeyModifiers, Page},
     },
 };
 
 /// Error type for issue thread page input handling operations.
 ///
-/// This error is returned when input handling operations fail, such as when
-/// interacting with the external editor for adding comments or appending
-/// comments to the event log.
+/// This error is returned when input handling operations fail, such as when appending a
+/// submitted comment to the event log.
 #[derive(Debug, Error)]
 #[error(debug)]
 pub struct IssueThreadPageInputError;
@@ -35,8 +34,7 @@ pub trait IssueThreadPageInput {
     ///
     /// # Errors
     ///
-    /// Returns an error if the input handling operation fails, such as when
-    /// using the external editor or appending to the event log.
+    /// Returns an error if appending a submitted comment to the event log fails.
     fn handle(
         &mut self,
         event: &Event,
@@ -46,95 +44,90 @@ pub trait IssueThreadPageInput {
 /// Implementation of issue thread page input handling for the application.
 ///
 /// This handler processes keyboard events when the issue thread page is focused.
-/// It supports navigation, help toggle, and comment creation via external editor.
+/// It supports navigation, help toggle, and comment creation via an in-TUI composer.
 impl IssueThreadPageInput for App {
     /// Process keyboard events for the issue thread page.
     ///
-    /// When the issue thread has focus, this handler processes the following keys:
-    /// - `q` or `Esc`: Return to the issue table
-    /// - `Down` or `j`: Move cursor down
-    /// - `Up` or `k`: Move cursor up
-    /// - `Ctrl+d`: Move cursor down by 10 items (page down)
-    /// - `Ctrl+u`: Move cursor up by 10 items (page up)
-    /// - `?`: Toggle help display
-    /// - `a`: Add a new comment using the external editor
+    /// When the issue thread has focus, this handler resolves the incoming key event to
+    /// an [`Action`] via `self.config.keymap` (see the `IssueThread` context) and dispatches
+    /// on it:
+    /// - `Back`: Return to the issue table
+    /// - `CursorNext`/`CursorPrev`: Move the cursor
+    /// - `PageDown`/`PageUp`: Move the cursor by 10 items
+    /// - `ToggleHelp`: Toggle help display
+    /// - `AddComment`: Open the in-TUI comment composer
+    ///
+    /// While the composer is open, keys are routed to it instead: `Ctrl-Enter` submits the
+    /// comment (see [`Self::submit_comment`]) and `Esc` cancels, discarding whatever was typed;
+    /// every other key (including plain `Enter`, which inserts a newline) is delegated to the
+    /// composer's `InputBoxState::handle_input`, the same way the issue table's filter input
+    /// handles its own keys. This is a transient sub-mode rather than a rebindable top-level
+    /// shortcut, so it isn't resolved through the keymap.
     ///
     /// If another page has focus, this handler will set focus to the issue thread
     /// page and stop event propagation.
     ///
     /// # Errors
     ///
-    /// Returns an error if:
-    /// - The external editor operation fails when adding a comment
-    /// - Appending the comment to the event log fails
+    /// Returns an error if appending the submitted comment to the event log fails.
     fn handle(
         &mut self,
         event: &Event,
     ) -> Result<EventPropagation, Report<IssueThreadPageInputError>> {
         match self.tuistate.focus() {
             Focus::IssueThread => {
-                if let (Some(key), mods) = (event.keypress(), event.modifiers()) {
-                    match (key, mods) {
-                        // Back to issue table
-                        (KeyCode::Char('q') | KeyCode::Esc, _) => {
+                if self.tuistate.issue_thread.composing() {
+                    if let Some(key) = event.keypress() {
+                        let mods = event.modifiers().unwrap_or(KeyModifiers::NONE);
+                        match key {
+                            KeyCode::Esc => {
+                                self.tuistate.issue_thread.close_compose();
+                                return Ok(EventPropagation::Stop);
+                            }
+                            KeyCode::Enter if mods.contains(KeyModifiers::CONTROL) => {
+                                self.submit_comment()?;
+                                return Ok(EventPropagation::Stop);
+                            }
+                            _ => (),
+                        }
+                    }
+                    return Ok(self
+                        .tuistate
+                        .issue_thread
+                        .comment_input_state_mut()
+                        .handle_input(event));
+                }
+                if let (Some(key), Some(mods)) = (event.keypress(), event.modifiers()) {
+                    let action = self.config.keymap.resolve(Context::IssueThread, key, mods);
+                    match action {
+                        Some(Action::Back) => {
                             self.tuistate.set_page(Page::IssueTable);
                             self.tuistate.set_focus(Focus::IssueTable);
                             self.tuistate.issue_thread.show_help = false;
                             return Ok(EventPropagation::Stop);
                         }
-                        // Cursor down
-                        (KeyCode::Down | KeyCode::Char('j'), _) => {
+                        Some(Action::CursorNext) => {
                             self.tuistate.issue_thread.cursor_next();
                             return Ok(EventPropagation::Stop);
                         }
-                        // Cursor up
-                        (KeyCode::Up | KeyCode::Char('k'), _) => {
+                        Some(Action::CursorPrev) => {
                             self.tuistate.issue_thread.cursor_previous();
                             return Ok(EventPropagation::Stop);
                         }
-                        // Cursor page down
-                        (KeyCode::Char('d'), Some(mods))
-                            if mods.contains(KeyModifiers::CONTROL) =>
-                        {
+                        Some(Action::PageDown) => {
                             self.tuistate.issue_thread.cursor_add(10);
                             return Ok(EventPropagation::Stop);
                         }
-                        // Cursor page up
-                        (KeyCode::Char('u'), Some(mods))
-                            if mods.contains(KeyModifiers::CONTROL) =>
-                        {
+                        Some(Action::PageUp) => {
                             self.tuistate.issue_thread.cursor_sub(10);
                             return Ok(EventPropagation::Stop);
                         }
-                        // Toggle help
-                        (KeyCode::Char('?'), _) => {
+                        Some(Action::ToggleHelp) => {
                             self.tuistate.issue_thread.toggle_help();
                             return Ok(EventPropagation::Stop);
                         }
-                        // Add new comment
-                        (KeyCode::Char('a'), _) => {
-                            let issue_id = self.tuistate.issue_thread.issue_id;
-                            let template = "Enter comment here.\n\n";
-                            self.external_editor
-                                .edit(template, "txt", move |app, response| {
-                                    if let Some(content) = response {
-                                        let content = content.trim().to_string();
-                                        if content.is_empty() {
-                                            return Ok(());
-                                        }
-                                        let comment = Comment {
-                                            parent_issue: issue_id,
-                                            content,
-                                            created: Timestamp::now(),
-                                            created_by: "TODO: current user email or from config"
-                                                .to_string(),
-                                        };
-                                        app.issues
-                                            .append_to_log(&app.args.event_log, comment)
-                                            .change_context(ExternalEditorError)?;
-                                    }
-                                    Ok(())
-                                });
+                        Some(Action::AddComment) => {
+                            self.tuistate.issue_thread.open_compose();
                             return Ok(EventPropagation::Stop);
                         }
                         _ => (),
@@ -149,3 +142,36 @@ impl IssueThreadPageInput for App {
         Ok(EventPropagation::Continue)
     }
 }
+
+impl App {
+    /// Submits the comment composer's current text, then closes it.
+    ///
+    /// An all-whitespace (or empty) comment is a silent no-op: the composer still closes, but
+    /// nothing is appended to the event log.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if appending the comment to the event log fails.
+    fn submit_comment(&mut self) -> Result<(), Report<IssueThreadPageInputError>> {
+        let issue_id = self.tuistate.issue_thread.issue_id;
+        let content = self
+            .tuistate
+            .issue_thread
+            .comment_input_state()
+            .text()
+            .trim()
+            .to_string();
+        self.tuistate.issue_thread.close_compose();
+        if content.is_empty() {
+            return Ok(());
+        }
+        let comment = Comment {
+            parent_issue: issue_id,
+            content,
+            created: Timestamp::now(),
+            created_by: self.config.resolve_comment_author(),
+        };
+        self.record_event(comment)
+            .change_context(IssueThreadPageInputError)
+    }
+}
@@ -1,12 +1,22 @@
 use tui_widget_list::ListState;
 
-use crate::feat::issue::IssueId;
+use crate::feat::{issue::IssueId, markdown::MarkdownCache, tui_widget::InputBoxState};
 
 #[derive(Debug, Default)]
 pub struct IssueThreadState {
     pub(in crate::feat::tui_issue_thread) list_state: ListState,
     pub(in crate::feat::tui_issue_thread) issue_id: IssueId,
     pub(in crate::feat::tui_issue_thread) show_help: bool,
+    /// Caches each comment's parsed/highlighted Markdown, keyed by content hash (see
+    /// `MarkdownCache`). Lives here rather than on `App` since it's only ever used while this
+    /// page is drawing comments.
+    pub(in crate::feat::tui_issue_thread) markdown_cache: MarkdownCache,
+    /// Whether the comment-composition popup (see [`Self::open_compose`]) is open.
+    pub(in crate::feat::tui_issue_thread) composing: bool,
+    /// The comment composer's text, kept in multi-line mode (the `InputBoxState` default) so
+    /// `Enter` inserts a newline instead of submitting; submission is bound to `Ctrl-Enter`
+    /// instead. Reset to empty whenever the popup closes, whether by cancel or submit.
+    pub(in crate::feat::tui_issue_thread) comment_input: InputBoxState,
 }
 
 impl IssueThreadState {
@@ -46,4 +56,29 @@ impl IssueThreadState {
     pub fn toggle_help(&mut self) {
         self.show_help = !self.show_help;
     }
+
+    pub fn composing(&self) -> bool {
+        self.composing
+    }
+
+    /// Opens the comment composer and gives it focus.
+    pub fn open_compose(&mut self) {
+        self.composing = true;
+        self.comment_input.set_focused(true);
+    }
+
+    /// Closes the comment composer and clears whatever was typed, whether it was submitted or
+    /// cancelled.
+    pub fn close_compose(&mut self) {
+        self.composing = false;
+        self.comment_input = InputBoxState::default();
+    }
+
+    pub fn comment_input_state(&self) -> &InputBoxState {
+        &self.comment_input
+    }
+
+    pub fn comment_input_state_mut(&mut self) -> &mut InputBoxState {
+        &mut self.comment_input
+    }
 }
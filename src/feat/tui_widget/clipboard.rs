@@ -0,0 +1,146 @@
+use std::io::Write as _;
+use std::process::{Command, Stdio};
+
+use error_stack::{Report, ResultExt};
+use wherror::Error;
+
+/// Error type for clipboard read/write operations.
+#[derive(Debug, Error)]
+#[error(debug)]
+pub struct ClipboardError;
+
+/// A clipboard backend: something that can read and write a single system-wide text register.
+///
+/// Implementations shell out to whatever clipboard tool is available for the current platform;
+/// [`InProcessClipboard`] is the fallback used when none is found, so copy/paste still works
+/// (within this process) in a minimal container with no clipboard tooling installed.
+pub trait ClipboardProvider {
+    /// Reads the current clipboard contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backing command could not be run or its output was not valid
+    /// UTF-8.
+    fn get_contents(&self) -> Result<String, Report<ClipboardError>>;
+
+    /// Overwrites the clipboard with `contents`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backing command could not be run or exited unsuccessfully.
+    fn set_contents(&self, contents: &str) -> Result<(), Report<ClipboardError>>;
+}
+
+/// Clipboard backed by a single in-memory register shared by the whole process.
+///
+/// A real system clipboard is itself a single shared resource, not one per widget instance, so
+/// the fallback register is a process-wide static rather than a field on `InputBoxState` (which
+/// also keeps `InputBoxState` cheaply `Clone`).
+struct InProcessClipboard;
+
+static IN_PROCESS_REGISTER: std::sync::LazyLock<std::sync::Mutex<String>> =
+    std::sync::LazyLock::new(|| std::sync::Mutex::new(String::new()));
+
+impl ClipboardProvider for InProcessClipboard {
+    fn get_contents(&self) -> Result<String, Report<ClipboardError>> {
+        let register = IN_PROCESS_REGISTER
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        Ok(register.clone())
+    }
+
+    fn set_contents(&self, contents: &str) -> Result<(), Report<ClipboardError>> {
+        let mut register = IN_PROCESS_REGISTER
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        register.clear();
+        register.push_str(contents);
+        Ok(())
+    }
+}
+
+/// Clipboard backed by an external command pair: one invocation to read, one to write.
+///
+/// The write command receives `contents` on stdin; the read command's stdout is taken as the
+/// clipboard contents.
+struct CommandClipboard {
+    get_cmd: (&'static str, &'static [&'static str]),
+    set_cmd: (&'static str, &'static [&'static str]),
+}
+
+impl ClipboardProvider for CommandClipboard {
+    fn get_contents(&self) -> Result<String, Report<ClipboardError>> {
+        let (cmd, args) = self.get_cmd;
+        let output = Command::new(cmd)
+            .args(args)
+            .output()
+            .change_context(ClipboardError)
+            .attach_with(|| format!("failed to run clipboard read command '{cmd}'"))?;
+        String::from_utf8(output.stdout)
+            .change_context(ClipboardError)
+            .attach("clipboard contents were not valid UTF-8")
+    }
+
+    fn set_contents(&self, contents: &str) -> Result<(), Report<ClipboardError>> {
+        let (cmd, args) = self.set_cmd;
+        let mut child = Command::new(cmd)
+            .args(args)
+            .stdin(Stdio::piped())
+            .spawn()
+            .change_context(ClipboardError)
+            .attach_with(|| format!("failed to run clipboard write command '{cmd}'"))?;
+        child
+            .stdin
+            .take()
+            .ok_or(ClipboardError)
+            .attach_with(|| format!("clipboard write command '{cmd}' did not expose stdin"))?
+            .write_all(contents.as_bytes())
+            .change_context(ClipboardError)
+            .attach_with(|| format!("failed to write to clipboard command '{cmd}'"))?;
+        child
+            .wait()
+            .change_context(ClipboardError)
+            .attach_with(|| format!("clipboard write command '{cmd}' failed"))?;
+        Ok(())
+    }
+}
+
+/// Checks whether `name` resolves to an executable file on `$PATH`.
+fn command_exists(name: &str) -> bool {
+    std::env::var_os("PATH")
+        .is_some_and(|path| std::env::split_paths(&path).any(|dir| dir.join(name).is_file()))
+}
+
+/// Picks a clipboard backend for the current environment.
+///
+/// Mirrors Helix's `get_clipboard_provider`: `pbcopy`/`pbpaste` on macOS, `clip`/`powershell
+/// Get-Clipboard` on Windows, `wl-copy`/`wl-paste` under Wayland (`$WAYLAND_DISPLAY` set) if
+/// installed, else `xclip` if installed. Falls back to [`InProcessClipboard`] when none of the
+/// above apply, so copy/paste still works without any system clipboard tool present.
+pub fn get_clipboard_provider() -> Box<dyn ClipboardProvider> {
+    if cfg!(target_os = "macos") {
+        return Box::new(CommandClipboard {
+            get_cmd: ("pbpaste", &[]),
+            set_cmd: ("pbcopy", &[]),
+        });
+    }
+    if cfg!(target_os = "windows") {
+        return Box::new(CommandClipboard {
+            get_cmd: ("powershell", &["-command", "Get-Clipboard"]),
+            set_cmd: ("clip", &[]),
+        });
+    }
+    if std::env::var_os("WAYLAND_DISPLAY").is_some() && command_exists("wl-copy") {
+        return Box::new(CommandClipboard {
+            get_cmd: ("wl-paste", &["--no-newline"]),
+            set_cmd: ("wl-copy", &[]),
+        });
+    }
+    if command_exists("xclip") {
+        return Box::new(CommandClipboard {
+            get_cmd: ("xclip", &["-selection", "clipboard", "-o"]),
+            set_cmd: ("xclip", &["-selection", "clipboard"]),
+        });
+    }
+    Box::new(InProcessClipboard)
+}
@@ -1,10 +1,23 @@
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    num::NonZeroUsize,
+    time::{Duration, Instant},
+};
 
-use ratatui::{layout::Rect, prelude::*, widgets::Widget};
+use ratatui::{layout::Rect, prelude::*, text::Text, widgets::Widget};
 use ropey::Rope;
+use unicode_segmentation::{GraphemeCursor, GraphemeIncomplete};
 use wherror::Error;
 
-use crate::feat::tui::{Event, EventExt, EventPropagation, KeyCode};
+use crate::feat::{
+    markdown,
+    tui::{Event, EventExt, EventPropagation, KeyCode, KeyModifiers},
+    tui_widget::clipboard::{self, ClipboardProvider},
+};
+
+/// Consecutive single-character inserts within this window of each other coalesce into one
+/// undo step, so `Ctrl-z` reverts a burst of typing rather than one keystroke at a time.
+const UNDO_GROUP_WINDOW: Duration = Duration::from_millis(300);
 
 /// Error type for input box operations.
 ///
@@ -14,52 +27,439 @@ use crate::feat::tui::{Event, EventExt, EventPropagation, KeyCode};
 #[error(debug)]
 pub struct InputBoxError;
 
+/// Returns the char index of the grapheme-cluster boundary before `char_idx` in `rope`.
+///
+/// Feeds `rope`'s chunks to a `unicode-segmentation` `GraphemeCursor` one at a time, supplying
+/// earlier chunks as requested via `GraphemeIncomplete::PrevChunk`/`PreContext`, so a multi-chunk
+/// rope is handled the same as a single-chunk one. Returns `0` if `char_idx` is already at the
+/// start of the text.
+fn prev_grapheme_boundary(rope: &Rope, char_idx: usize) -> usize {
+    let byte_idx = rope.char_to_byte(char_idx);
+    if byte_idx == 0 {
+        return 0;
+    }
+
+    let (mut chunk, mut chunk_byte_idx, _, _) = rope.chunk_at_byte(byte_idx);
+    let mut cursor = GraphemeCursor::new(byte_idx, rope.len_bytes(), true);
+    loop {
+        match cursor.prev_boundary(chunk, chunk_byte_idx) {
+            Ok(None) => return 0,
+            Ok(Some(boundary)) => return rope.byte_to_char(boundary),
+            Err(GraphemeIncomplete::PrevChunk) => {
+                let (prev_chunk, prev_chunk_byte_idx, _, _) =
+                    rope.chunk_at_byte(chunk_byte_idx.saturating_sub(1));
+                chunk = prev_chunk;
+                chunk_byte_idx = prev_chunk_byte_idx;
+            }
+            Err(GraphemeIncomplete::PreContext(ctx_byte_idx)) => {
+                let (ctx_chunk, ctx_chunk_byte_idx, _, _) =
+                    rope.chunk_at_byte(ctx_byte_idx.saturating_sub(1));
+                cursor.provide_context(ctx_chunk, ctx_chunk_byte_idx);
+            }
+            Err(other) => unreachable!("unexpected grapheme cursor error: {other:?}"),
+        }
+    }
+}
+
+/// Returns the char index of the grapheme-cluster boundary after `char_idx` in `rope`.
+///
+/// Mirrors [`prev_grapheme_boundary`], walking forward instead: `GraphemeIncomplete::NextChunk`
+/// advances to the following chunk and `PreContext` supplies an earlier chunk the cursor needs
+/// to resolve the boundary. Returns `rope.len_chars()` if `char_idx` is already at the end.
+fn next_grapheme_boundary(rope: &Rope, char_idx: usize) -> usize {
+    let byte_idx = rope.char_to_byte(char_idx);
+    let len_bytes = rope.len_bytes();
+    if byte_idx >= len_bytes {
+        return rope.len_chars();
+    }
+
+    let (mut chunk, mut chunk_byte_idx, _, _) = rope.chunk_at_byte(byte_idx);
+    let mut cursor = GraphemeCursor::new(byte_idx, len_bytes, true);
+    loop {
+        match cursor.next_boundary(chunk, chunk_byte_idx) {
+            Ok(None) => return rope.len_chars(),
+            Ok(Some(boundary)) => return rope.byte_to_char(boundary),
+            Err(GraphemeIncomplete::NextChunk) => {
+                chunk_byte_idx += chunk.len();
+                (chunk, ..) = rope.chunk_at_byte(chunk_byte_idx);
+            }
+            Err(GraphemeIncomplete::PreContext(ctx_byte_idx)) => {
+                let (ctx_chunk, ctx_chunk_byte_idx, _, _) =
+                    rope.chunk_at_byte(ctx_byte_idx.saturating_sub(1));
+                cursor.provide_context(ctx_chunk, ctx_chunk_byte_idx);
+            }
+            Err(other) => unreachable!("unexpected grapheme cursor error: {other:?}"),
+        }
+    }
+}
+
+/// Coarse word-class used to find word boundaries: a "word" is a maximal run of chars in the
+/// same class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WordClass {
+    Whitespace,
+    Alphanumeric,
+    Punctuation,
+}
+
+fn word_class(ch: char) -> WordClass {
+    if ch.is_whitespace() {
+        WordClass::Whitespace
+    } else if ch.is_alphanumeric() || ch == '_' {
+        WordClass::Alphanumeric
+    } else {
+        WordClass::Punctuation
+    }
+}
+
+/// Returns the char index of the start of the word before `char_idx`, skipping any whitespace
+/// `char_idx` is sitting just after first (so `Ctrl-Left`/`Ctrl-w` land on the word itself, not
+/// the gap before it).
+fn prev_word_boundary(rope: &Rope, char_idx: usize) -> usize {
+    let mut idx = char_idx;
+    while idx > 0 && word_class(rope.char(idx - 1)) == WordClass::Whitespace {
+        idx -= 1;
+    }
+    if idx > 0 {
+        let class = word_class(rope.char(idx - 1));
+        while idx > 0 && word_class(rope.char(idx - 1)) == class {
+            idx -= 1;
+        }
+    }
+    idx
+}
+
+/// Returns the char index just past the end of the word after `char_idx`, skipping any
+/// whitespace `char_idx` sits in first.
+fn next_word_boundary(rope: &Rope, char_idx: usize) -> usize {
+    let len = rope.len_chars();
+    let mut idx = char_idx;
+    while idx < len && word_class(rope.char(idx)) == WordClass::Whitespace {
+        idx += 1;
+    }
+    if idx < len {
+        let class = word_class(rope.char(idx));
+        while idx < len && word_class(rope.char(idx)) == class {
+            idx += 1;
+        }
+    }
+    idx
+}
+
+/// Returns the `[start, end)` char range of logical line `line_idx`'s content, excluding the
+/// trailing `\n` (if any).
+///
+/// Used both to clamp Up/Down cursor motion to a target line's length and to split the rope
+/// into per-line spans for rendering.
+fn line_char_range(rope: &Rope, line_idx: usize) -> (usize, usize) {
+    let start = rope.line_to_char(line_idx);
+    let end = if line_idx + 1 < rope.len_lines() {
+        rope.line_to_char(line_idx + 1)
+    } else {
+        rope.len_chars()
+    };
+    let end = if end > start && rope.char(end - 1) == '\n' {
+        end - 1
+    } else {
+        end
+    };
+    (start, end)
+}
+
+/// A minimal, self-inverse description of one committed edit: the text inserted, or the text
+/// removed, at a position.
+///
+/// Storing the text on both variants (rather than just a char count for inserts) is what lets
+/// [`Edit::invert`] reconstruct the opposite edit with no access to the `Rope`, which is what
+/// `redo` needs: it has only the *undo* edit to work from, by that point the rope no longer
+/// holds whatever text an undone insert had contributed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Edit {
+    Insert { at: usize, text: String },
+    Remove { at: usize, text: String },
+}
+
+impl Edit {
+    /// Applies this edit to `rope`, returning the cursor position it leaves behind.
+    fn apply(&self, rope: &mut Rope) -> usize {
+        match self {
+            Edit::Insert { at, text } => {
+                rope.insert(*at, text);
+                *at + text.chars().count()
+            }
+            Edit::Remove { at, text } => {
+                rope.remove(*at..*at + text.chars().count());
+                *at
+            }
+        }
+    }
+
+    /// The edit that reverses this one.
+    fn invert(&self) -> Edit {
+        match self {
+            Edit::Insert { at, text } => Edit::Remove {
+                at: *at,
+                text: text.clone(),
+            },
+            Edit::Remove { at, text } => Edit::Insert {
+                at: *at,
+                text: text.clone(),
+            },
+        }
+    }
+}
+
+/// One node in the input box's undo/redo revision tree.
+///
+/// `revisions[0]` is an unused root sentinel representing the empty starting state (its
+/// `inverse` is never applied); every real edit lives at an index `>= 1`, which is why
+/// `last_child` can use `NonZeroUsize`. `parent` is the revision this one was committed on top
+/// of, and `inverse` undoes the edit that produced this revision from `parent`.
+#[derive(Clone, Debug)]
+struct Revision {
+    parent: usize,
+    last_child: Option<NonZeroUsize>,
+    inverse: Edit,
+    timestamp: Instant,
+}
+
 /// State container for the input box widget.
 ///
-/// This struct holds the current text content, cursor position, and focus state
-/// of an input box. It manages the internal state required for text input and
+/// This struct holds the current text content, cursor position, focus state, and undo/redo
+/// history of an input box. It manages the internal state required for text input and
 /// cursor manipulation.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct InputBoxState {
     text: Rope,
     cursor: usize,
     is_focused: bool,
+    /// Undo/redo history. See [`Revision`].
+    revisions: Vec<Revision>,
+    /// Index into `revisions` of the currently-applied edit (`0` is the unedited root).
+    current: usize,
+    /// The other end of an in-progress selection; `None` means nothing is selected. The
+    /// selection itself is the (order-independent) range between this and `cursor`.
+    anchor: Option<usize>,
+    /// Whether pasted text may introduce newlines. Defaults to `true`. A caller presenting a
+    /// single-line input (such as a filter box) should set this `false`; note this governs
+    /// paste only; typed `Enter` always inserts a newline, so single-line callers are expected
+    /// to intercept `Enter` themselves before delegating to `handle_input`, the way the issue
+    /// table's filter box uses it to move focus instead.
+    multiline: bool,
+}
+
+impl Default for InputBoxState {
+    fn default() -> Self {
+        Self {
+            text: Rope::default(),
+            cursor: 0,
+            is_focused: false,
+            revisions: vec![Revision {
+                parent: 0,
+                last_child: None,
+                inverse: Edit::Insert {
+                    at: 0,
+                    text: String::new(),
+                },
+                timestamp: Instant::now(),
+            }],
+            current: 0,
+            anchor: None,
+            multiline: true,
+        }
+    }
 }
 
 impl InputBoxState {
     /// Handles keyboard input events for the input box.
     ///
-    /// Processes character input, backspace, and cursor movement keys.
+    /// Processes character input, backspace, newline, and cursor movement keys.
     /// Returns `EventPropagation::Stop` when the event is handled by this input box,
     /// or `EventPropagation::Continue` when the event should be passed to other handlers.
     ///
     /// - Character input inserts the character at the cursor position and advances the cursor
-    /// - Backspace deletes the character before the cursor (if any)
-    /// - Left/Right arrows move the cursor within the text bounds
+    /// - Enter inserts a newline, switching the input box into a second (or later) visual line
+    /// - Backspace deletes the whole grapheme cluster before the cursor (if any)
+    /// - Left/Right arrows move the cursor to the previous/next grapheme-cluster boundary
+    /// - Up/Down arrows move the cursor to the same column on the adjacent line, clamped to
+    ///   that line's length
+    /// - `Ctrl-z` undoes and `Ctrl-y`/`Ctrl-r` redoes the most recent edit; see [`Self::undo`]
+    /// - `Shift-Left`/`Shift-Right` grow a selection from the cursor's position when pressed;
+    ///   plain Left/Right/Up/Down or an edit clears it
+    /// - `Ctrl-c`/`Ctrl-x` copy/cut the selection (if any) to the system clipboard; `Ctrl-v`
+    ///   pastes the clipboard contents at the cursor, replacing embedded newlines with spaces
+    ///   unless this input box is in multi-line mode (see [`Self::set_multiline`])
+    /// - `Home`/`Ctrl-a` move the cursor to the start of the current line; `End`/`Ctrl-e` move
+    ///   it to the end
+    /// - `Ctrl-Left`/`Ctrl-Right` move the cursor by one word, and `Delete` deletes the whole
+    ///   grapheme cluster after the cursor (if any)
+    /// - `Ctrl-w` deletes the word before the cursor
     pub fn handle_input(&mut self, event: &Event) -> EventPropagation {
         if let Some(key) = event.keypress() {
+            let mods = event.modifiers().unwrap_or(KeyModifiers::NONE);
             match key {
+                KeyCode::Char('z') if mods.contains(KeyModifiers::CONTROL) => {
+                    self.undo();
+                    return EventPropagation::Stop;
+                }
+                KeyCode::Char('y' | 'r') if mods.contains(KeyModifiers::CONTROL) => {
+                    self.redo();
+                    return EventPropagation::Stop;
+                }
+                KeyCode::Char('c') if mods.contains(KeyModifiers::CONTROL) => {
+                    self.copy_selection();
+                    return EventPropagation::Stop;
+                }
+                KeyCode::Char('x') if mods.contains(KeyModifiers::CONTROL) => {
+                    self.cut_selection();
+                    return EventPropagation::Stop;
+                }
+                KeyCode::Char('v') if mods.contains(KeyModifiers::CONTROL) => {
+                    self.paste();
+                    return EventPropagation::Stop;
+                }
+                KeyCode::Char('a') if mods.contains(KeyModifiers::CONTROL) => {
+                    self.anchor = None;
+                    let line_idx = self.text.char_to_line(self.cursor);
+                    self.cursor = self.text.line_to_char(line_idx);
+                    return EventPropagation::Stop;
+                }
+                KeyCode::Char('e') if mods.contains(KeyModifiers::CONTROL) => {
+                    self.anchor = None;
+                    let line_idx = self.text.char_to_line(self.cursor);
+                    let (_, end) = line_char_range(&self.text, line_idx);
+                    self.cursor = end;
+                    return EventPropagation::Stop;
+                }
+                KeyCode::Char('w') if mods.contains(KeyModifiers::CONTROL) => {
+                    self.anchor = None;
+                    let boundary = prev_word_boundary(&self.text, self.cursor);
+                    if boundary < self.cursor {
+                        let removed = self.text.slice(boundary..self.cursor).to_string();
+                        self.text.remove(boundary..self.cursor);
+                        self.cursor = boundary;
+                        self.commit_edit(Edit::Remove {
+                            at: boundary,
+                            text: removed,
+                        });
+                    }
+                    return EventPropagation::Stop;
+                }
                 KeyCode::Char(ch) => {
-                    self.text.insert_char(self.cursor, ch);
+                    self.anchor = None;
+                    let at = self.cursor;
+                    self.text.insert_char(at, ch);
+                    self.cursor += 1;
+                    if !self.try_coalesce_insert(at, ch) {
+                        self.commit_edit(Edit::Insert {
+                            at,
+                            text: ch.to_string(),
+                        });
+                    }
+                    return EventPropagation::Stop;
+                }
+                KeyCode::Enter => {
+                    self.anchor = None;
+                    let at = self.cursor;
+                    self.text.insert_char(at, '\n');
                     self.cursor += 1;
+                    self.commit_edit(Edit::Insert {
+                        at,
+                        text: "\n".to_string(),
+                    });
                     return EventPropagation::Stop;
                 }
                 KeyCode::Backspace => {
-                    let text_len = self.text.len_chars();
-
-                    if text_len > 0 {
-                        self.text.remove(self.cursor.saturating_sub(1)..self.cursor);
-                        self.cursor = self.cursor.saturating_sub(1);
+                    self.anchor = None;
+                    if self.cursor > 0 {
+                        let prev_boundary = prev_grapheme_boundary(&self.text, self.cursor);
+                        let removed = self.text.slice(prev_boundary..self.cursor).to_string();
+                        self.text.remove(prev_boundary..self.cursor);
+                        self.cursor = prev_boundary;
+                        self.commit_edit(Edit::Remove {
+                            at: prev_boundary,
+                            text: removed,
+                        });
+                    }
+                    return EventPropagation::Stop;
+                }
+                KeyCode::Delete => {
+                    self.anchor = None;
+                    if self.cursor < self.text.len_chars() {
+                        let next_boundary = next_grapheme_boundary(&self.text, self.cursor);
+                        let removed = self.text.slice(self.cursor..next_boundary).to_string();
+                        self.text.remove(self.cursor..next_boundary);
+                        self.commit_edit(Edit::Remove {
+                            at: self.cursor,
+                            text: removed,
+                        });
+                    }
+                    return EventPropagation::Stop;
+                }
+                KeyCode::Home => {
+                    self.anchor = None;
+                    let line_idx = self.text.char_to_line(self.cursor);
+                    self.cursor = self.text.line_to_char(line_idx);
+                    return EventPropagation::Stop;
+                }
+                KeyCode::End => {
+                    self.anchor = None;
+                    let line_idx = self.text.char_to_line(self.cursor);
+                    let (_, end) = line_char_range(&self.text, line_idx);
+                    self.cursor = end;
+                    return EventPropagation::Stop;
+                }
+                KeyCode::Left if mods.contains(KeyModifiers::CONTROL) => {
+                    self.anchor = None;
+                    self.cursor = prev_word_boundary(&self.text, self.cursor);
+                    return EventPropagation::Stop;
+                }
+                KeyCode::Right if mods.contains(KeyModifiers::CONTROL) => {
+                    self.anchor = None;
+                    self.cursor = next_word_boundary(&self.text, self.cursor);
+                    return EventPropagation::Stop;
+                }
+                KeyCode::Left if mods.contains(KeyModifiers::SHIFT) => {
+                    self.anchor.get_or_insert(self.cursor);
+                    self.cursor = prev_grapheme_boundary(&self.text, self.cursor);
+                    return EventPropagation::Stop;
+                }
+                KeyCode::Right if mods.contains(KeyModifiers::SHIFT) => {
+                    self.anchor.get_or_insert(self.cursor);
+                    if self.cursor < self.text.len_chars() {
+                        self.cursor = next_grapheme_boundary(&self.text, self.cursor);
                     }
                     return EventPropagation::Stop;
                 }
                 KeyCode::Left => {
-                    self.cursor = self.cursor.saturating_sub(1);
+                    self.anchor = None;
+                    self.cursor = prev_grapheme_boundary(&self.text, self.cursor);
                     return EventPropagation::Stop;
                 }
                 KeyCode::Right => {
+                    self.anchor = None;
                     if self.cursor < self.text.len_chars() {
-                        self.cursor += 1;
+                        self.cursor = next_grapheme_boundary(&self.text, self.cursor);
+                    }
+                    return EventPropagation::Stop;
+                }
+                KeyCode::Up => {
+                    self.anchor = None;
+                    let line_idx = self.text.char_to_line(self.cursor);
+                    if line_idx > 0 {
+                        let col = self.cursor - self.text.line_to_char(line_idx);
+                        let (start, end) = line_char_range(&self.text, line_idx - 1);
+                        self.cursor = (start + col).min(end);
+                    }
+                    return EventPropagation::Stop;
+                }
+                KeyCode::Down => {
+                    self.anchor = None;
+                    let line_idx = self.text.char_to_line(self.cursor);
+                    if line_idx + 1 < self.text.len_lines() {
+                        let col = self.cursor - self.text.line_to_char(line_idx);
+                        let (start, end) = line_char_range(&self.text, line_idx + 1);
+                        self.cursor = (start + col).min(end);
                     }
                     return EventPropagation::Stop;
                 }
@@ -69,6 +469,137 @@ impl InputBoxState {
         EventPropagation::Continue
     }
 
+    /// Folds a just-typed single character into the in-progress revision if it continues an
+    /// uninterrupted, recent run of typing, so a burst of keystrokes undoes as one step.
+    ///
+    /// Returns `true` if the character was folded into `revisions[self.current]` (in which case
+    /// the caller must not also call [`Self::commit_edit`] for it).
+    fn try_coalesce_insert(&mut self, at: usize, ch: char) -> bool {
+        if self.current == 0 {
+            return false;
+        }
+        let revision = &mut self.revisions[self.current];
+        if revision.timestamp.elapsed() >= UNDO_GROUP_WINDOW {
+            return false;
+        }
+        let Edit::Remove {
+            at: group_at,
+            text: group_text,
+        } = &mut revision.inverse
+        else {
+            return false;
+        };
+        if at != *group_at + group_text.chars().count() {
+            return false;
+        }
+        group_text.push(ch);
+        revision.timestamp = Instant::now();
+        true
+    }
+
+    /// Records `edit` (already applied to `self.text`) as a new revision on top of the current
+    /// one, and makes it current.
+    fn commit_edit(&mut self, edit: Edit) {
+        let new_index = self.revisions.len();
+        self.revisions.push(Revision {
+            parent: self.current,
+            last_child: None,
+            inverse: edit.invert(),
+            timestamp: Instant::now(),
+        });
+        self.revisions[self.current].last_child = NonZeroUsize::new(new_index);
+        self.current = new_index;
+    }
+
+    /// Reverts the current revision's edit, restoring the cursor to where it was beforehand, and
+    /// moves `current` to its parent. Does nothing if there is nothing to undo.
+    pub fn undo(&mut self) {
+        if self.current == 0 {
+            return;
+        }
+        let revision = self.revisions[self.current].clone();
+        self.cursor = revision.inverse.apply(&mut self.text);
+        self.current = revision.parent;
+    }
+
+    /// Re-applies the most recently undone edit (the current revision's last child), if any.
+    pub fn redo(&mut self) {
+        let Some(child) = self.revisions[self.current].last_child else {
+            return;
+        };
+        let child = child.get();
+        let forward = self.revisions[child].inverse.invert();
+        self.cursor = forward.apply(&mut self.text);
+        self.current = child;
+    }
+
+    /// Returns the current selection as an order-independent `[start, end)` char range, or
+    /// `None` if nothing is selected.
+    fn selection_range(&self) -> Option<(usize, usize)> {
+        self.anchor.map(|anchor| {
+            if anchor <= self.cursor {
+                (anchor, self.cursor)
+            } else {
+                (self.cursor, anchor)
+            }
+        })
+    }
+
+    /// Copies the selection (if any) to the system clipboard. Best-effort: a clipboard failure
+    /// is silently ignored, the same way a missing clipboard tool falls back to the in-process
+    /// register rather than surfacing an error to the user.
+    fn copy_selection(&mut self) {
+        if let Some((start, end)) = self.selection_range() {
+            let selected = self.text.slice(start..end).to_string();
+            let _ = clipboard::get_clipboard_provider().set_contents(&selected);
+        }
+    }
+
+    /// Copies the selection (if any) to the system clipboard, then removes it from the text as
+    /// a single undoable edit.
+    fn cut_selection(&mut self) {
+        if let Some((start, end)) = self.selection_range() {
+            let removed = self.text.slice(start..end).to_string();
+            let _ = clipboard::get_clipboard_provider().set_contents(&removed);
+            self.text.remove(start..end);
+            self.cursor = start;
+            self.anchor = None;
+            self.commit_edit(Edit::Remove {
+                at: start,
+                text: removed,
+            });
+        }
+    }
+
+    /// Inserts the system clipboard's contents at the cursor as a single undoable edit.
+    ///
+    /// Embedded newlines are replaced with spaces unless this input box is in multi-line mode
+    /// (see [`Self::set_multiline`]), so pasting multi-line text into a single-line box doesn't
+    /// silently turn it into several lines.
+    fn paste(&mut self) {
+        let Ok(contents) = clipboard::get_clipboard_provider().get_contents() else {
+            return;
+        };
+        if contents.is_empty() {
+            return;
+        }
+        let contents = if self.multiline {
+            contents
+        } else {
+            contents.replace('\n', " ")
+        };
+        self.anchor = None;
+        let at = self.cursor;
+        self.text.insert(at, &contents);
+        self.cursor = at + contents.chars().count();
+        self.commit_edit(Edit::Insert { at, text: contents });
+    }
+
+    /// Sets whether pasted text may introduce newlines; see the `multiline` field doc comment.
+    pub fn set_multiline(&mut self, multiline: bool) {
+        self.multiline = multiline;
+    }
+
     /// Sets the focus state of the input box.
     ///
     /// When focused, the input box will respond to keyboard input and display
@@ -77,12 +608,20 @@ impl InputBoxState {
         self.is_focused = focused;
     }
 
-    /// Returns the current text content of the input box.
+    /// Returns the full text content of the input box, including any embedded newlines.
     ///
     /// Returns the text as a `Cow` to allow zero-copy conversions while also
     /// supporting owned string operations when needed.
     pub fn text(&self) -> Cow<'_, str> {
-        self.text.line(0).into()
+        Cow::from(self.text.slice(..))
+    }
+
+    /// Returns an iterator over the input box's text, one `RopeSlice` per line.
+    ///
+    /// Useful for callers (such as comment submission) that want to work with the text
+    /// line-by-line rather than as a single joined string.
+    pub fn lines(&self) -> ropey::iter::Lines<'_> {
+        self.text.lines()
     }
 }
 
@@ -134,6 +673,10 @@ impl<'a> InputBox<'a> {
 /// with appropriate styling based on the focus state. The cursor is displayed
 /// as a reversed character when focused, and the entire input box is highlighted
 /// when focused.
+///
+/// Each rope line becomes one row, word-wrapped to the available width via
+/// `markdown::wrap_text` (the same wrapping used to lay out comments in the issue thread), and
+/// up to `area.height` rows are drawn. The prefix is only shown on the first row.
 impl StatefulWidget for InputBox<'_> {
     type State = InputBoxState;
 
@@ -141,23 +684,47 @@ impl StatefulWidget for InputBox<'_> {
         let is_focused = state.is_focused;
 
         // Define the cursor's appearance.
-        let cursor_style = Style::default().add_modifier(Modifier::REVERSED);
-
-        // Generate the styled line with the visible cursor.
-        let mut input_line =
-            format_text_with_cursor(&state.text, state.cursor, cursor_style, is_focused);
+        let mut cursor_style = Style::default().add_modifier(Modifier::REVERSED);
+        if !is_focused {
+            cursor_style = Style::default();
+        }
+        let selection_style = Style::default().bg(Color::DarkGray);
 
-        // Create the query indicator
-        let mut full_line = self.prefix;
+        let prefix_width: usize = self
+            .prefix
+            .iter()
+            .map(|span| span.content.chars().count())
+            .sum();
+        let wrap_width = (area.width as usize).saturating_sub(prefix_width).max(1);
 
-        // Merge the indicator with the user's text
-        let full_line = {
-            full_line.append(&mut input_line);
+        let display_text = build_display_text(
+            &state.text,
+            state.cursor,
+            state.selection_range(),
+            cursor_style,
+            selection_style,
+        );
+        let mut rows = markdown::wrap_text(&display_text, wrap_width);
 
-            Line::from(full_line).style(apply_focus_highlight(is_focused))
-        };
+        // The prefix only belongs on the input box's very first visual row.
+        if let Some(first_row) = rows.first_mut() {
+            let mut spans = self.prefix;
+            spans.append(&mut first_row.spans);
+            *first_row = Line::from(spans);
+        } else {
+            rows.push(Line::from(self.prefix));
+        }
 
-        Widget::render(full_line, area, buf);
+        let focus_style = apply_focus_highlight(is_focused);
+        for (row_idx, row) in rows.into_iter().take(area.height as usize).enumerate() {
+            let row_area = Rect {
+                x: area.x,
+                y: area.y + row_idx as u16,
+                width: area.width,
+                height: 1,
+            };
+            Widget::render(row.style(focus_style), row_area, buf);
+        }
     }
 }
 
@@ -173,50 +740,505 @@ fn apply_focus_highlight(is_focused: bool) -> Style {
     }
 }
 
-/// Formats text with a visible cursor position.
+/// Returns the style to paint char index `idx` with, or `None` for plain text: `cursor_style` if
+/// `idx` falls within the whole grapheme cluster under the cursor, else `selection_style` if
+/// `idx` falls within `selection`.
+fn char_style(
+    text: &Rope,
+    idx: usize,
+    cursor: usize,
+    selection: Option<(usize, usize)>,
+    cursor_style: Style,
+    selection_style: Style,
+) -> Option<Style> {
+    if idx == cursor {
+        return Some(cursor_style);
+    }
+    if cursor < idx && idx < next_grapheme_boundary(text, cursor) {
+        return Some(cursor_style);
+    }
+    if let Some((start, end)) = selection
+        && start <= idx
+        && idx < end
+    {
+        return Some(selection_style);
+    }
+    None
+}
+
+/// Builds the spans for one logical line's content range `[start, end)`, embedding the cursor's
+/// visual highlight when `cursor` falls within this line, and `selection`'s (if any and if it
+/// overlaps this line).
 ///
-/// This function takes text and a cursor position, then returns a vector of styled spans
-/// representing the text with the cursor visually embedded. When the cursor is at the end
-/// of the text or when the text is empty, it appends a styled space to represent the cursor.
-/// When the cursor is in the middle, it splits the text and applies the cursor style to
-/// the character at the cursor position.
+/// When `cursor == end` (end of this line's content, including the single-line case where
+/// `end == text.len_chars()`), a styled trailing space represents the cursor. Otherwise the
+/// range is walked char-by-char, grouping consecutive chars that share a style into one span, so
+/// a multi-codepoint grapheme under the cursor is never split across spans. A line with no
+/// cursor or selection on it renders as a single plain span.
+fn line_spans_with_cursor(
+    text: &Rope,
+    start: usize,
+    end: usize,
+    cursor: usize,
+    selection: Option<(usize, usize)>,
+    cursor_style: Style,
+    selection_style: Style,
+) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    if end > start {
+        let mut run_start = start;
+        let mut run_style = char_style(
+            text,
+            start,
+            cursor,
+            selection,
+            cursor_style,
+            selection_style,
+        );
+        for idx in (start + 1)..end {
+            let style = char_style(text, idx, cursor, selection, cursor_style, selection_style);
+            if style != run_style {
+                spans.push(styled_span(text, run_start, idx, run_style));
+                run_start = idx;
+                run_style = style;
+            }
+        }
+        spans.push(styled_span(text, run_start, end, run_style));
+    }
+
+    if cursor == end {
+        spans.push(Span::styled(" ".to_string(), cursor_style));
+    } else if spans.is_empty() {
+        spans.push(Span::from(String::new()));
+    }
+
+    spans
+}
+
+/// Builds a single span from `text[start..end]`, styled with `style` if present.
+fn styled_span(text: &Rope, start: usize, end: usize, style: Option<Style>) -> Span<'static> {
+    let content = text.slice(start..end).to_string();
+    match style {
+        Some(style) => Span::styled(content, style),
+        None => Span::from(content),
+    }
+}
+
+/// Builds a `Text` with one line per rope line, the cursor and selection (if any) visually
+/// embedded on whichever lines they fall on.
 ///
-/// The cursor is only visually styled when `is_focused` is true. When not focused,
-/// the cursor style is neutral.
-fn format_text_with_cursor(
+/// Fed to `markdown::wrap_text` by the renderer so multi-line input wraps the same way comments
+/// do in the issue thread.
+fn build_display_text(
     text: &Rope,
-    cursor_pos: usize,
+    cursor: usize,
+    selection: Option<(usize, usize)>,
     cursor_style: Style,
-    is_focused: bool,
-) -> Vec<Span<'_>> {
-    let mut cursor_style = cursor_style;
-    // Clamp the cursor position to be within the valid range of the text's character length.
-    let cursor_pos = cursor_pos.min(text.len_chars());
-    if !is_focused {
-        cursor_style = Style::default();
-    }
-
-    // Case 1: The cursor is at the end of the text or the text is empty.
-    // We render the existing text and append a styled space to represent the cursor.
-    if cursor_pos == text.len_chars() {
-        let text_slice = text.slice(..);
-        let text_span = Span::from(Cow::from(text_slice));
-        let cursor_span = Span::styled(" ", cursor_style); // Styled space
-        vec![text_span, cursor_span]
-    }
-    // Case 2: The cursor is somewhere in the middle of the text.
-    // We split the text into three parts: before, at, and after the cursor.
-    else {
-        // Slice the text into its three components.
-        let text_before_cursor = text.slice(..cursor_pos);
-        let text_at_cursor = text.slice(cursor_pos..=cursor_pos);
-        let text_after_cursor = text.slice(cursor_pos + 1..);
-
-        // Create styled spans for each component.
-        let span_before = Span::from(Cow::from(text_before_cursor));
-        let span_at_cursor = Span::styled(Cow::from(text_at_cursor), cursor_style);
-        let span_after = Span::from(Cow::from(text_after_cursor));
-
-        vec![span_before, span_at_cursor, span_after]
+    selection_style: Style,
+) -> Text<'static> {
+    let cursor = cursor.min(text.len_chars());
+    let lines = (0..text.len_lines())
+        .map(|line_idx| {
+            let (start, end) = line_char_range(text, line_idx);
+            Line::from(line_spans_with_cursor(
+                text,
+                start,
+                end,
+                cursor,
+                selection,
+                cursor_style,
+                selection_style,
+            ))
+        })
+        .collect::<Vec<_>>();
+    Text::from(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    // "a👩‍👩‍👧‍👦b": family emoji built from a 7-codepoint ZWJ sequence.
+    #[case("a👩‍👩‍👧‍👦b", 1, 0)]
+    #[case("a👩‍👩‍👧‍👦b", 8, 1)]
+    #[case("a👩‍👩‍👧‍👦b", 0, 0)]
+    // "é" as "e" + combining acute accent (2 chars, 1 grapheme).
+    #[case("ae\u{0301}b", 1, 0)]
+    #[case("ae\u{0301}b", 3, 1)]
+    fn test_prev_grapheme_boundary(
+        #[case] text: &str,
+        #[case] char_idx: usize,
+        #[case] expected: usize,
+    ) {
+        let rope = Rope::from(text);
+        assert_eq!(prev_grapheme_boundary(&rope, char_idx), expected);
+    }
+
+    #[rstest]
+    #[case("a👩‍👩‍👧‍👦b", 0, 1)]
+    #[case("a👩‍👩‍👧‍👦b", 1, 8)]
+    #[case("a👩‍👩‍👧‍👦b", 9, 9)]
+    #[case("ae\u{0301}b", 0, 1)]
+    #[case("ae\u{0301}b", 1, 3)]
+    fn test_next_grapheme_boundary(
+        #[case] text: &str,
+        #[case] char_idx: usize,
+        #[case] expected: usize,
+    ) {
+        let rope = Rope::from(text);
+        assert_eq!(next_grapheme_boundary(&rope, char_idx), expected);
+    }
+
+    #[test]
+    fn test_backspace_deletes_whole_grapheme() {
+        use crate::feat::tui::KeyEvent;
+
+        let mut state = InputBoxState {
+            text: Rope::from("ae\u{0301}b"),
+            cursor: 3,
+            is_focused: true,
+            ..Default::default()
+        };
+        state.handle_input(&Event::Key(KeyEvent::from(KeyCode::Backspace)));
+        assert_eq!(state.text(), "ab");
+        assert_eq!(state.cursor, 1);
+    }
+
+    fn key_event(code: KeyCode) -> Event {
+        use crate::feat::tui::KeyEvent;
+
+        Event::Key(KeyEvent::from(code))
+    }
+
+    #[test]
+    fn test_enter_inserts_newline() {
+        let mut state = InputBoxState {
+            text: Rope::from("ab"),
+            cursor: 1,
+            is_focused: true,
+            ..Default::default()
+        };
+        state.handle_input(&key_event(KeyCode::Enter));
+        assert_eq!(state.text(), "a\nb");
+        assert_eq!(state.cursor, 2);
+    }
+
+    #[test]
+    fn test_up_down_preserve_column() {
+        // Lines: "abc" (0..3), "de" (4..6), "fghi" (7..11).
+        let mut state = InputBoxState {
+            text: Rope::from("abc\nde\nfghi"),
+            cursor: 10, // column 3 on the last line
+            is_focused: true,
+            ..Default::default()
+        };
+
+        // Moving up onto the shorter "de" line clamps to its end (column 2).
+        state.handle_input(&key_event(KeyCode::Up));
+        assert_eq!(state.cursor, 6);
+
+        // Moving up again lands on "abc" at the same column (2).
+        state.handle_input(&key_event(KeyCode::Up));
+        assert_eq!(state.cursor, 2);
+
+        // Up from the first line is a no-op.
+        state.handle_input(&key_event(KeyCode::Up));
+        assert_eq!(state.cursor, 2);
+
+        // Moving down returns to "de", clamped from column 2 to its length.
+        state.handle_input(&key_event(KeyCode::Down));
+        assert_eq!(state.cursor, 6);
+    }
+
+    #[test]
+    fn test_lines_accessor() {
+        let state = InputBoxState {
+            text: Rope::from("foo\nbar"),
+            cursor: 0,
+            is_focused: false,
+            ..Default::default()
+        };
+        let lines: Vec<String> = state.lines().map(|l| l.to_string()).collect();
+        assert_eq!(lines, vec!["foo\n".to_string(), "bar".to_string()]);
+    }
+
+    fn key_event_with_mods(code: KeyCode, mods: KeyModifiers) -> Event {
+        use crate::feat::tui::KeyEvent;
+
+        Event::Key(KeyEvent::new(code, mods))
+    }
+
+    #[test]
+    fn test_undo_reverts_last_edit_and_redo_reapplies_it() {
+        let mut state = InputBoxState::default();
+        state.handle_input(&key_event(KeyCode::Char('a')));
+        state.handle_input(&key_event(KeyCode::Enter));
+        assert_eq!(state.text(), "a\n");
+
+        state.handle_input(&key_event_with_mods(
+            KeyCode::Char('z'),
+            KeyModifiers::CONTROL,
+        ));
+        assert_eq!(state.text(), "a");
+        assert_eq!(state.cursor, 1);
+
+        state.handle_input(&key_event_with_mods(
+            KeyCode::Char('z'),
+            KeyModifiers::CONTROL,
+        ));
+        assert_eq!(state.text(), "");
+        assert_eq!(state.cursor, 0);
+
+        // Undoing with nothing left to undo is a no-op.
+        state.handle_input(&key_event_with_mods(
+            KeyCode::Char('z'),
+            KeyModifiers::CONTROL,
+        ));
+        assert_eq!(state.text(), "");
+
+        state.handle_input(&key_event_with_mods(
+            KeyCode::Char('y'),
+            KeyModifiers::CONTROL,
+        ));
+        state.handle_input(&key_event_with_mods(
+            KeyCode::Char('r'),
+            KeyModifiers::CONTROL,
+        ));
+        assert_eq!(state.text(), "a\n");
+        assert_eq!(state.cursor, 2);
+    }
+
+    #[test]
+    fn test_undo_redo_round_trips_a_backspace() {
+        let mut state = InputBoxState {
+            text: Rope::from("ab"),
+            cursor: 2,
+            is_focused: true,
+            ..Default::default()
+        };
+        state.handle_input(&key_event(KeyCode::Backspace));
+        assert_eq!(state.text(), "a");
+
+        state.undo();
+        assert_eq!(state.text(), "ab");
+        assert_eq!(state.cursor, 2);
+
+        state.redo();
+        assert_eq!(state.text(), "a");
+        assert_eq!(state.cursor, 1);
+    }
+
+    #[test]
+    fn test_consecutive_char_inserts_coalesce_into_one_undo_step() {
+        let mut state = InputBoxState::default();
+        state.handle_input(&key_event(KeyCode::Char('a')));
+        state.handle_input(&key_event(KeyCode::Char('b')));
+        state.handle_input(&key_event(KeyCode::Char('c')));
+        assert_eq!(state.text(), "abc");
+        assert_eq!(state.revisions.len(), 2); // root + one coalesced revision
+
+        state.undo();
+        assert_eq!(state.text(), "");
+    }
+
+    #[test]
+    fn test_typing_after_undo_starts_a_new_revision_not_a_coalesced_one() {
+        let mut state = InputBoxState::default();
+        state.handle_input(&key_event(KeyCode::Char('a')));
+        state.undo();
+        state.handle_input(&key_event(KeyCode::Char('b')));
+        assert_eq!(state.text(), "b");
+
+        state.undo();
+        assert_eq!(state.text(), "");
+    }
+
+    // These clipboard tests rely on `get_clipboard_provider` falling back to the in-process
+    // register, which is what happens in a sandboxed test run with no `xclip`/`wl-copy`/etc.
+    // installed and no `$WAYLAND_DISPLAY` set.
+
+    #[test]
+    fn test_shift_right_selects_then_ctrl_c_ctrl_v_round_trips() {
+        let mut state = InputBoxState {
+            text: Rope::from("abcdef"),
+            cursor: 0,
+            is_focused: true,
+            ..Default::default()
+        };
+        for _ in 0..3 {
+            state.handle_input(&key_event_with_mods(KeyCode::Right, KeyModifiers::SHIFT));
+        }
+        assert_eq!(state.selection_range(), Some((0, 3)));
+
+        state.handle_input(&key_event_with_mods(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL,
+        ));
+        // Copying doesn't disturb the text or collapse the selection.
+        assert_eq!(state.text(), "abcdef");
+        assert_eq!(state.selection_range(), Some((0, 3)));
+
+        state.handle_input(&key_event(KeyCode::Right));
+        assert_eq!(state.selection_range(), None);
+        state.handle_input(&key_event_with_mods(
+            KeyCode::Char('v'),
+            KeyModifiers::CONTROL,
+        ));
+        assert_eq!(state.text(), "abcabcdef");
+    }
+
+    #[test]
+    fn test_ctrl_x_cuts_selection_and_is_undoable() {
+        let mut state = InputBoxState {
+            text: Rope::from("abcdef"),
+            cursor: 1,
+            is_focused: true,
+            ..Default::default()
+        };
+        for _ in 0..2 {
+            state.handle_input(&key_event_with_mods(KeyCode::Right, KeyModifiers::SHIFT));
+        }
+        assert_eq!(state.selection_range(), Some((1, 3)));
+
+        state.handle_input(&key_event_with_mods(
+            KeyCode::Char('x'),
+            KeyModifiers::CONTROL,
+        ));
+        assert_eq!(state.text(), "adef");
+        assert_eq!(state.cursor, 1);
+        assert_eq!(state.selection_range(), None);
+
+        state.undo();
+        assert_eq!(state.text(), "abcdef");
+
+        state.handle_input(&key_event_with_mods(
+            KeyCode::Char('v'),
+            KeyModifiers::CONTROL,
+        ));
+        assert_eq!(state.text(), "abcbcdef");
+    }
+
+    #[test]
+    fn test_paste_replaces_newlines_when_not_multiline() {
+        let mut state = InputBoxState::default();
+        state.set_multiline(false);
+
+        state.handle_input(&key_event(KeyCode::Char('a')));
+        state.handle_input(&key_event_with_mods(KeyCode::Left, KeyModifiers::SHIFT));
+        state.handle_input(&key_event_with_mods(
+            KeyCode::Char('x'),
+            KeyModifiers::CONTROL,
+        ));
+        assert_eq!(state.text(), "");
+
+        // Seed the in-process clipboard register via a multi-line box, then paste into the
+        // single-line one.
+        let mut source = InputBoxState::default();
+        source.handle_input(&key_event(KeyCode::Char('x')));
+        source.handle_input(&key_event(KeyCode::Enter));
+        source.handle_input(&key_event(KeyCode::Char('y')));
+        source.handle_input(&key_event_with_mods(KeyCode::Left, KeyModifiers::SHIFT));
+        source.handle_input(&key_event_with_mods(KeyCode::Left, KeyModifiers::SHIFT));
+        source.handle_input(&key_event_with_mods(KeyCode::Left, KeyModifiers::SHIFT));
+        source.handle_input(&key_event_with_mods(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL,
+        ));
+
+        state.handle_input(&key_event_with_mods(
+            KeyCode::Char('v'),
+            KeyModifiers::CONTROL,
+        ));
+        assert_eq!(state.text(), "x y");
+    }
+
+    #[test]
+    fn test_home_end_move_to_line_boundaries() {
+        let mut state = InputBoxState {
+            text: Rope::from("hello\nworld"),
+            cursor: 8,
+            is_focused: true,
+            ..Default::default()
+        };
+        state.handle_input(&key_event(KeyCode::Home));
+        assert_eq!(state.cursor, 6);
+
+        state.handle_input(&key_event(KeyCode::End));
+        assert_eq!(state.cursor, 11);
+
+        state.cursor = 8;
+        state.handle_input(&key_event_with_mods(
+            KeyCode::Char('a'),
+            KeyModifiers::CONTROL,
+        ));
+        assert_eq!(state.cursor, 6);
+
+        state.handle_input(&key_event_with_mods(
+            KeyCode::Char('e'),
+            KeyModifiers::CONTROL,
+        ));
+        assert_eq!(state.cursor, 11);
+    }
+
+    #[test]
+    fn test_delete_removes_grapheme_after_cursor() {
+        let mut state = InputBoxState {
+            text: Rope::from("abc"),
+            cursor: 1,
+            is_focused: true,
+            ..Default::default()
+        };
+        state.handle_input(&key_event(KeyCode::Delete));
+        assert_eq!(state.text(), "ac");
+        assert_eq!(state.cursor, 1);
+
+        state.undo();
+        assert_eq!(state.text(), "abc");
+    }
+
+    #[test]
+    fn test_ctrl_left_ctrl_right_move_by_word() {
+        let mut state = InputBoxState {
+            text: Rope::from("foo  bar.baz"),
+            cursor: 0,
+            is_focused: true,
+            ..Default::default()
+        };
+        state.handle_input(&key_event_with_mods(KeyCode::Right, KeyModifiers::CONTROL));
+        assert_eq!(state.cursor, 3); // end of "foo"
+
+        state.handle_input(&key_event_with_mods(KeyCode::Right, KeyModifiers::CONTROL));
+        assert_eq!(state.cursor, 8); // end of "bar"
+
+        state.handle_input(&key_event_with_mods(KeyCode::Right, KeyModifiers::CONTROL));
+        assert_eq!(state.cursor, 9); // end of "."
+
+        state.handle_input(&key_event_with_mods(KeyCode::Left, KeyModifiers::CONTROL));
+        assert_eq!(state.cursor, 8); // back to start of "."
+
+        state.handle_input(&key_event_with_mods(KeyCode::Left, KeyModifiers::CONTROL));
+        assert_eq!(state.cursor, 5); // start of "bar"
+    }
+
+    #[test]
+    fn test_ctrl_w_deletes_word_before_cursor() {
+        let mut state = InputBoxState {
+            text: Rope::from("foo bar  "),
+            cursor: 9,
+            is_focused: true,
+            ..Default::default()
+        };
+        state.handle_input(&key_event_with_mods(
+            KeyCode::Char('w'),
+            KeyModifiers::CONTROL,
+        ));
+        assert_eq!(state.text(), "foo ");
+        assert_eq!(state.cursor, 4);
+
+        state.undo();
+        assert_eq!(state.text(), "foo bar  ");
     }
 }
@@ -0,0 +1,54 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Paragraph, Widget, Wrap};
+
+/// A centered, word-wrapped overlay for showing one value in full, styled like
+/// [`super::HelpPopup`].
+#[derive(Clone)]
+pub struct TextPopup<'a> {
+    content: &'a str,
+    title: Option<&'a str>,
+}
+
+impl<'a> TextPopup<'a> {
+    pub fn new(content: &'a str) -> Self {
+        Self {
+            content,
+            title: None,
+        }
+    }
+
+    #[must_use]
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+}
+
+impl Widget for TextPopup<'_> {
+    fn render(self, _: Rect, buf: &mut Buffer) {
+        let area = buf.area();
+        let popup_width = (area.width * 3 / 4).clamp(20, area.width);
+        let popup_height = (area.height * 2 / 3).clamp(5, area.height);
+        let popup_rect = Rect {
+            x: area.x + (area.width.saturating_sub(popup_width)) / 2,
+            y: area.y + (area.height.saturating_sub(popup_height)) / 2,
+            width: popup_width,
+            height: popup_height,
+        };
+
+        buf.set_style(popup_rect, Style::default().bg(Color::Rgb(30, 30, 30)));
+
+        let title = self.title.unwrap_or(" Value ");
+        let block = Block::default()
+            .title(title)
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::DarkGray));
+
+        let paragraph = Paragraph::new(self.content)
+            .style(Style::default().fg(Color::Gray))
+            .wrap(Wrap { trim: false })
+            .block(block);
+        paragraph.render(popup_rect, buf);
+    }
+}
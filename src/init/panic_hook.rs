@@ -1,33 +1,95 @@
 use std::{
-    io::{self, stdout},
+    io,
     panic::{set_hook, take_hook},
+    sync::{
+        Once,
+        atomic::{AtomicBool, Ordering},
+    },
 };
 
 use ratatui::crossterm::{
+    cursor,
+    event::{DisableBracketedPaste, DisableMouseCapture},
     execute,
     terminal::{LeaveAlternateScreen, disable_raw_mode},
 };
 
-/// Initializes a panic hook that restores the terminal UI before propagating a panic.
+/// Whether the active `Tui` backend currently has mouse capture enabled, kept in sync by
+/// `feat::tui::Tui::enter_raw_mode` so [`restore_tui`] knows whether to emit `DisableMouseCapture`
+/// when it runs from a panic hook, where there's no `&Tui` around to ask.
+static MOUSE_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Whether the active `Tui` backend currently has bracketed paste enabled; see [`MOUSE_ENABLED`].
+static PASTE_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Whether the active `Tui` backend's writer is stdout rather than stderr, kept in sync by
+/// `feat::tui::Tui::enter_raw_mode` (see `Tui::with_writer`) so [`restore_tui`] sends its
+/// recovery escape codes to the stream that's actually in alternate screen/raw mode. `Tui` is
+/// generic over any `W: Write` (a pipe, an in-memory buffer for tests, ...), but none of those
+/// have a real terminal for a panic hook to restore, so `enter_raw_mode` only ever reports
+/// `true` for an actual `std::io::Stdout` writer and this otherwise defaults to stderr.
+static ACTIVE_STREAM_IS_STDOUT: AtomicBool = AtomicBool::new(false);
+
+static INSTALLED: Once = Once::new();
+
+/// Records whether the terminal backend currently has mouse capture/bracketed paste enabled, so a
+/// panic mid-session only disables what was actually turned on. Called by
+/// `feat::tui::Tui::enter_raw_mode` every time it (re-)enters raw mode.
+pub fn set_backend_flags(mouse: bool, paste: bool) {
+    MOUSE_ENABLED.store(mouse, Ordering::Relaxed);
+    PASTE_ENABLED.store(paste, Ordering::Relaxed);
+}
+
+/// Records which real stream the active `Tui` backend's writer is, so [`restore_tui`] knows
+/// whether to target stdout or stderr instead of assuming stderr. Called by
+/// `feat::tui::Tui::enter_raw_mode` every time it (re-)enters raw mode.
+pub fn set_active_stream_is_stdout(is_stdout: bool) {
+    ACTIVE_STREAM_IS_STDOUT.store(is_stdout, Ordering::Relaxed);
+}
+
+/// Installs a panic hook that restores the terminal before the previous hook (which prints the
+/// panic message/backtrace) runs, so a panic inside a widget's render closure never leaves the
+/// terminal in raw mode with the alternate screen active and the cursor hidden — a panic message
+/// printed into that state is invisible until the shell is reset by hand. Safe to call more than
+/// once; only the first call installs anything.
+///
+/// # Notes
 ///
-/// This function sets up a custom panic handler that ensures the terminal is properly
-/// restored to its original state (leaving alternate screen and disabling raw mode)
-/// before the panic is propagated to the previous hook. This prevents the terminal
-/// from being left in an unusable state when a panic occurs.
+/// Restoration here writes directly to stderr rather than going through a `Tui`'s terminal
+/// backend, since a panic can strike with that backend borrowed or left in an inconsistent state.
 pub fn init() {
-    let original_hook = take_hook();
-    set_hook(Box::new(move |panic_info| {
-        // intentionally ignore errors here since we're already in a panic
-        let _ = restore_tui();
-        original_hook(panic_info);
-    }));
+    INSTALLED.call_once(|| {
+        let original_hook = take_hook();
+        set_hook(Box::new(move |panic_info| {
+            // intentionally ignore errors here since we're already in a panic
+            let _ = restore_tui();
+            original_hook(panic_info);
+        }));
+    });
 }
 
-/// Restores the terminal UI to its original state by disabling raw mode and leaving the alternate screen.
+/// Restores the terminal to its original state: disables mouse capture/bracketed paste if
+/// [`set_backend_flags`] says they were on, leaves the alternate screen, shows the cursor, and
+/// disables raw mode. Writes to whichever stream [`set_active_stream_is_stdout`] last reported,
+/// matching `feat::tui::Tui`'s backend instead of assuming stderr.
 ///
-/// Returns an error if either disabling raw mode or leaving the alternate screen fails.
+/// Returns an error if any of the terminal commands fail.
 pub fn restore_tui() -> io::Result<()> {
+    if ACTIVE_STREAM_IS_STDOUT.load(Ordering::Relaxed) {
+        restore_tui_on(io::stdout())
+    } else {
+        restore_tui_on(io::stderr())
+    }
+}
+
+fn restore_tui_on(mut stream: impl io::Write) -> io::Result<()> {
+    if PASTE_ENABLED.load(Ordering::Relaxed) {
+        execute!(stream, DisableBracketedPaste)?;
+    }
+    if MOUSE_ENABLED.load(Ordering::Relaxed) {
+        execute!(stream, DisableMouseCapture)?;
+    }
+    execute!(stream, LeaveAlternateScreen, cursor::Show)?;
     disable_raw_mode()?;
-    execute!(stdout(), LeaveAlternateScreen)?;
     Ok(())
 }